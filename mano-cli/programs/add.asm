@@ -0,0 +1,9 @@
+   ORG 0    /Origin of program is location 0
+   LDA A    /Load operand from location A
+   ADD B    /Add operand from location B
+   STA C    /Store sum in location C
+   HLT      /Halt computer
+A, DEC 83   /Decimal operand
+B, DEC -23  /Decimal operand
+C, DEC 0    /Sum stored in location C
+   END      /End of symbolic program
@@ -0,0 +1,7 @@
+   ORG 0      /Call a subroutine forever
+LOP, BSA SUB  /Branch to subroutine
+   BUN LOP    /Call it again
+SUB, HEX 0    /Subroutine entry, holds the return address
+   INC        /Increment AC
+   BUN SUB i  /Return to main program
+   END        /End of symbolic program
@@ -0,0 +1,21 @@
+   ORG 0       /Copy a 16-word block through indirect pointers, forever
+BEG, LDA SA    /Load source block address
+   STA SP      /Reset source pointer
+   LDA DA      /Load destination block address
+   STA DP      /Reset destination pointer
+   LDA NBR     /Load minus sixteen
+   STA CTR     /Reset counter
+CPY, LDA SP i  /Load a word from the source block
+   STA DP i    /Store it in the destination block
+   ISZ SP      /Increment source pointer
+   ISZ DP      /Increment destination pointer
+   ISZ CTR     /Increment counter, skip when the block is done
+   BUN CPY     /Copy the next word
+   BUN BEG     /Start the block again
+SP,  HEX 0     /Source pointer
+DP,  HEX 0     /Destination pointer
+SA,  HEX 100   /Source block address
+DA,  HEX 200   /Destination block address
+NBR, DEC -16   /Constant to initialise counter
+CTR, HEX 0     /Reserved for a counter
+   END         /End of symbolic program
@@ -0,0 +1,4 @@
+   ORG 0      /Tight loop, two instructions per iteration
+LOP, INC      /Increment AC
+   BUN LOP    /Branch back forever
+   END        /End of symbolic program
@@ -0,0 +1,22 @@
+   ORG 0      /Sum the four numbers in the table
+   LDA ADS    /Load first address of operands
+   STA PTR    /Store in pointer
+   LDA NBR    /Load minus four
+   STA CTR    /Store in counter
+   CLA        /Clear accumulator
+LOP, ADD PTR i /Add an operand to AC
+   ISZ PTR    /Increment pointer
+   ISZ CTR    /Increment counter
+   BUN LOP    /Repeat loop again
+   STA SUM    /Store sum
+   HLT        /Halt computer
+ADS, HEX 10   /First address of operands
+PTR, HEX 0    /Reserved for a pointer
+NBR, DEC -4   /Constant to initialise counter
+CTR, HEX 0    /Reserved for a counter
+SUM, HEX 0    /Sum is stored here
+   DEC 10     /Operands start at location 10
+   DEC 20     /Second operand
+   DEC 30     /Third operand
+   DEC 40     /Last operand
+   END        /End of symbolic program
@@ -0,0 +1,19 @@
+   ORG 0      /Shift X and Y left four places via a subroutine
+   LDA X      /Load X
+   BSA SH4    /Branch to subroutine
+   STA X      /Store shifted number
+   LDA Y      /Load Y
+   BSA SH4    /Branch to subroutine again
+   STA Y      /Store shifted number
+   HLT        /Halt computer
+X,  HEX 1234  /First operand
+Y,  HEX 4321  /Second operand
+SH4, HEX 0    /Subroutine entry, holds the return address
+   CIL        /Circulate left once
+   CIL        /Circulate left twice
+   CIL        /Circulate left three times
+   CIL        /Circulate left four times
+   AND MSK    /Set AC(13-16) to zero
+   BUN SH4 i  /Return to main program
+MSK, HEX FFF0 /Mask operand
+   END        /End of symbolic program
@@ -0,0 +1,101 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+use mano_lib::machine::Machine;
+use mano_lib::message::Messages;
+
+/// A synthetic program that never halts, used to measure simulator throughput.
+pub struct Workload {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub source: &'static str,
+}
+
+pub const WORKLOADS: &[Workload] = &[
+    Workload {
+        name: "tight",
+        description: "INC/BUN loop, register-reference and branch only",
+        source: include_str!("../programs/bench/tight.asm"),
+    },
+    Workload {
+        name: "copy",
+        description: "Block copy through indirect pointers with an ISZ counter",
+        source: include_str!("../programs/bench/copy.asm"),
+    },
+    Workload {
+        name: "call",
+        description: "BSA subroutine call and indirect return",
+        source: include_str!("../programs/bench/call.asm"),
+    },
+];
+
+pub fn find(name: &str) -> Option<&'static Workload> {
+    WORKLOADS.iter().find(|workload| workload.name == name)
+}
+
+pub struct Report {
+    pub instructions: u64,
+    pub cycles: u64,
+    pub elapsed: Duration,
+}
+
+impl Report {
+    pub fn instructions_per_second(&self) -> f64 {
+        self.instructions as f64 / self.elapsed.as_secs_f64()
+    }
+}
+
+fn prime(workload: &Workload) -> Result<Machine> {
+    let mut machine = Machine::new();
+    let messages = machine.prime(workload.source.lines().map(String::from).collect());
+    if messages.has_errors() {
+        bail!("Workload '{}' failed to assemble", workload.name);
+    }
+    Ok(machine)
+}
+
+/// Run `workload` for `instructions` complete instructions.
+///
+/// A first, untimed pass counts the clock cycles those instructions take by
+/// watching SC return to 0. The timed pass then replays exactly that many
+/// cycles on a fresh machine without reading state back, so only `tick` is
+/// measured.
+pub fn run(workload: &Workload, instructions: u64) -> Result<Report> {
+    let mut machine = prime(workload)?;
+    let mut messages = Messages::new();
+    let mut cycles = 0;
+    let mut completed = 0;
+
+    while completed < instructions {
+        machine.tick(&mut messages);
+        cycles += 1;
+
+        if messages.has_errors() {
+            bail!("Workload '{}' stopped with an error after {} cycles", workload.name, cycles);
+        }
+        if machine.is_halted() {
+            bail!("Workload '{}' halted after {} cycles", workload.name, cycles);
+        }
+        messages.entries.clear();
+
+        if machine.get_state().sequence_counter == 0 {
+            completed += 1;
+        }
+    }
+
+    let mut machine = prime(workload)?;
+    let mut messages = Messages::new();
+
+    let start = Instant::now();
+    for _ in 0..cycles {
+        machine.tick(&mut messages);
+        messages.entries.clear();
+    }
+    let elapsed = start.elapsed();
+
+    Ok(Report {
+        instructions,
+        cycles,
+        elapsed,
+    })
+}
@@ -0,0 +1,281 @@
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, Write};
+
+use anyhow::{bail, Context, Result};
+use mano_lib::machine::{Machine, MachineState};
+use mano_lib::message::Messages;
+use mano_common::radix::Radix;
+
+use crate::utils::print_messages;
+
+const HELP: &str = "\
+Commands:
+  step, s                 Advance one clock cycle
+  stepi, si               Execute until the next instruction boundary
+  continue, c             Run until HLT, an error or a breakpoint
+  break, b <addr>         Set a breakpoint (no address lists them)
+  delete, d <addr>        Remove a breakpoint (no address removes all)
+  print, p [REG]          Show a register (PC AC IR AR DR E SC) or all of them
+  mem, m <addr>[..<end>]  Dump memory, end exclusive
+  reset                   Reload the program into a fresh machine
+  help, h                 Show this help
+  quit, q                 Exit the debugger
+Addresses are hex, with an optional 0x prefix, or octal with a 0o prefix.
+Not supported yet: `set` for writing registers and memory (the machine has no
+write API) and breakpoints on labels (the assembler doesn't expose its symbols).";
+
+/// Upper bound on clock cycles for a single `stepi`, in case SC never returns to 0.
+const MAX_INSTRUCTION_TICKS: usize = 64;
+
+pub struct Debugger {
+    program: Vec<String>,
+    machine: Machine,
+    breakpoints: BTreeSet<u16>,
+    max_steps: usize,
+    verbose: bool,
+}
+
+impl Debugger {
+    /// Prime a machine with `program`, returning `None` if assembly fails.
+    pub fn new(program: Vec<String>, max_steps: usize, verbose: bool) -> Option<Self> {
+        let mut machine = Machine::new();
+        let messages = machine.prime(program.clone());
+        print_messages(&messages, verbose);
+
+        if messages.has_errors() {
+            return None;
+        }
+
+        Some(Self {
+            program,
+            machine,
+            breakpoints: BTreeSet::new(),
+            max_steps,
+            verbose,
+        })
+    }
+
+    /// Read commands from stdin until `quit` or end of input.
+    pub fn run(&mut self) -> Result<()> {
+        println!("Type `help` for a list of commands.");
+
+        let stdin = io::stdin();
+        let mut lines = stdin.lock().lines();
+
+        loop {
+            print!("(mano) ");
+            io::stdout().flush()?;
+
+            let Some(line) = lines.next() else {
+                println!();
+                return Ok(());
+            };
+            let line = line?;
+
+            let mut words = line.split_whitespace();
+            let Some(command) = words.next() else {
+                continue;
+            };
+            let args: Vec<&str> = words.collect();
+
+            let result = match command.to_lowercase().as_str() {
+                "step" | "s" => self.step(),
+                "stepi" | "si" => self.step_instruction(),
+                "continue" | "c" => self.continue_run(),
+                "break" | "b" => self.set_breakpoint(args.first().copied()),
+                "delete" | "d" => self.delete_breakpoint(args.first().copied()),
+                "print" | "p" => self.print_register(args.first().copied()),
+                "mem" | "m" => self.dump_memory(args.first().copied()),
+                "reset" => self.reset(),
+                "help" | "h" => {
+                    println!("{}", HELP);
+                    Ok(())
+                }
+                "quit" | "q" => return Ok(()),
+                other => Err(anyhow::anyhow!("Unknown command '{}', type `help` for a list", other)),
+            };
+
+            if let Err(err) = result {
+                println!("ERR: {:#}", err);
+            }
+        }
+    }
+
+    /// Advance one clock cycle, returning `false` if the machine can't continue.
+    fn tick(&mut self) -> bool {
+        if self.machine.is_halted() {
+            println!("Program has halted, use `reset` to start again.");
+            return false;
+        }
+
+        let mut messages = Messages::new();
+        self.machine.tick(&mut messages);
+        print_messages(&messages, self.verbose);
+
+        !messages.has_errors() && !self.machine.is_halted()
+    }
+
+    fn step(&mut self) -> Result<()> {
+        self.tick();
+        self.print_status();
+        Ok(())
+    }
+
+    fn step_instruction(&mut self) -> Result<()> {
+        for _ in 0..MAX_INSTRUCTION_TICKS {
+            if !self.tick() || self.machine.get_state().sequence_counter == 0 {
+                break;
+            }
+        }
+        self.print_status();
+        Ok(())
+    }
+
+    fn continue_run(&mut self) -> Result<()> {
+        let mut step_count = 0;
+
+        while self.tick() {
+            step_count += 1;
+
+            let state = self.machine.get_state();
+            if state.sequence_counter == 0 && self.breakpoints.contains(&state.program_counter) {
+                println!("Breakpoint at 0x{:04X} after {} steps.", state.program_counter, step_count);
+                break;
+            }
+
+            if step_count >= self.max_steps {
+                println!("Program exceeded {} steps. Stopping to prevent infinite loop.", self.max_steps);
+                break;
+            }
+        }
+
+        if self.machine.is_halted() {
+            println!("Program halted.");
+        }
+        self.print_status();
+        Ok(())
+    }
+
+    fn set_breakpoint(&mut self, arg: Option<&str>) -> Result<()> {
+        let Some(arg) = arg else {
+            if self.breakpoints.is_empty() {
+                println!("No breakpoints set.");
+            }
+            for addr in &self.breakpoints {
+                println!("  0x{:04X}", addr);
+            }
+            return Ok(());
+        };
+
+        let Ok(addr) = parse_number(arg) else {
+            bail!("'{}' isn't an address (label breakpoints need the assembler's symbol table)", arg);
+        };
+
+        self.breakpoints.insert(addr);
+        println!("Breakpoint set at 0x{:04X}.", addr);
+        Ok(())
+    }
+
+    fn delete_breakpoint(&mut self, arg: Option<&str>) -> Result<()> {
+        match arg {
+            None => {
+                self.breakpoints.clear();
+                println!("All breakpoints removed.");
+            }
+            Some(arg) => {
+                let addr = parse_number(arg)?;
+                if !self.breakpoints.remove(&addr) {
+                    bail!("No breakpoint at 0x{:04X}", addr);
+                }
+                println!("Breakpoint at 0x{:04X} removed.", addr);
+            }
+        }
+        Ok(())
+    }
+
+    fn print_register(&self, arg: Option<&str>) -> Result<()> {
+        let state = self.machine.get_state();
+
+        let Some(name) = arg else {
+            self.print_status();
+            return Ok(());
+        };
+
+        let value = register(&state, name)
+            .with_context(|| format!("Unknown register '{}'", name))?;
+        println!("{} = 0x{:04X} ({})", name.to_uppercase(), value, value as i16);
+        Ok(())
+    }
+
+    fn dump_memory(&self, arg: Option<&str>) -> Result<()> {
+        let arg = arg.context("Usage: mem <addr>[..<end>]")?;
+
+        let (start, end) = match arg.split_once("..") {
+            Some((start, end)) => (parse_number(start)?, parse_number(end)?),
+            None => {
+                let start = parse_number(arg)?;
+                let end = start.checked_add(1).with_context(|| format!("Invalid memory range {}", arg))?;
+                (start, end)
+            }
+        };
+
+        if end <= start || end > 0x1000 {
+            bail!("Invalid memory range {}", arg);
+        }
+
+        let memory = self.machine.load_memory_range(start, end - start);
+        for (row, words) in memory.chunks(8).enumerate() {
+            print!("{:04X}:", start as usize + row * 8);
+            for word in words {
+                print!(" {:04X}", word);
+            }
+            println!();
+        }
+        Ok(())
+    }
+
+    fn reset(&mut self) -> Result<()> {
+        let mut machine = Machine::new();
+        let messages = machine.prime(self.program.clone());
+        print_messages(&messages, self.verbose);
+
+        self.machine = machine;
+        println!("Machine reset.");
+        self.print_status();
+        Ok(())
+    }
+
+    fn print_status(&self) {
+        let state = self.machine.get_state();
+        println!(
+            "PC=0x{:04X} AC=0x{:04X} IR=0x{:04X} AR=0x{:04X} DR=0x{:04X} E={} SC={}{}",
+            state.program_counter,
+            state.accumulator,
+            state.instruction_register,
+            state.address_register,
+            state.data_register,
+            state.extend_register,
+            state.sequence_counter,
+            if state.is_halted { " [HALTED]" } else { "" },
+        );
+    }
+}
+
+fn register(state: &MachineState, name: &str) -> Option<u16> {
+    let value = match name.to_uppercase().as_str() {
+        "PC" => state.program_counter,
+        "AC" => state.accumulator,
+        "IR" => state.instruction_register,
+        "AR" => state.address_register,
+        "DR" => state.data_register,
+        "E" => state.extend_register,
+        "SC" => u16::from(state.sequence_counter),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Parse a hex number, or an octal one with a `0o` prefix.
+fn parse_number(text: &str) -> Result<u16> {
+    Radix::Hex.parse(text).with_context(|| format!("Invalid number '{}'", text))
+}
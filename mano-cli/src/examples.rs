@@ -0,0 +1,28 @@
+/// An assembly program bundled with the CLI.
+pub struct Example {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub source: &'static str,
+}
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "add",
+        description: "Add two numbers and store the sum",
+        source: include_str!("../programs/add.asm"),
+    },
+    Example {
+        name: "loop",
+        description: "Sum a table of numbers with an ISZ counter and indirect pointer",
+        source: include_str!("../programs/loop.asm"),
+    },
+    Example {
+        name: "subroutine",
+        description: "Shift two words left four places using a BSA subroutine",
+        source: include_str!("../programs/subroutine.asm"),
+    },
+];
+
+pub fn find(name: &str) -> Option<&'static Example> {
+    EXAMPLES.iter().find(|example| example.name == name)
+}
@@ -1,41 +1,290 @@
-use std::fs::File;
+use std::collections::HashMap;
+use std::fs::{self, File};
 use std::io::{BufRead, BufReader};
-use std::path::Path;
-use clap::Parser;
-use anyhow::Result;
+use std::path::{Path, PathBuf};
+use clap::{Args, Parser, Subcommand};
+use anyhow::{bail, ensure, Context, Result};
 use mano_lib::machine::Machine;
 use mano_lib::message::Messages;
+use mano_common::limits::DEFAULT_MAX_STEPS;
+use mano_common::radix::Radix;
 
+mod bench;
+mod debugger;
+mod examples;
+mod object;
 mod utils;
-use utils::{print_messages, print_source_program, print_assembled_program, print_machine_state};
+use utils::{print_messages, print_source_program, print_assembled_program, print_machine_state, print_disassembly};
 
 #[derive(Parser)]
 #[command(name = "mano")]
 #[command(about = "Mano Machine Simulator")]
 struct Cli {
-    /// Assembly file to run
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Assemble a program and write the machine code to a file
+    Assemble(AssembleArgs),
+
+    /// Assemble and run a program
+    Run(RunArgs),
+
+    /// Validate a program without running it
+    Check(CheckArgs),
+
+    /// Debug a program interactively
+    Debug(DebugArgs),
+
+    /// Decode a hex machine code file back into instructions
+    Disasm(DisasmArgs),
+
+    /// List the embedded example programs
+    Examples,
+
+    /// Measure simulator throughput on built-in workloads
+    Bench(BenchArgs),
+}
+
+#[derive(Args)]
+struct AssembleArgs {
+    /// Assembly file, or directory of .asm/.txt files, to assemble
+    file: String,
+
+    /// Output file (defaults to the input file with the format's extension)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Also assemble files in subdirectories when given a directory
+    #[arg(short, long)]
+    recursive: bool,
+
+    /// Machine code output format
+    #[arg(short, long, value_enum, default_value = "hex")]
+    format: object::Format,
+
+    /// Show verbose output (including debug messages)
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Assembly file, or machine code file (.hex, .bin, .ihx), to run
+    #[arg(required_unless_present = "example", conflicts_with = "example")]
+    file: Option<String>,
+
+    /// Treat the file as machine code even without a known extension
+    #[arg(short, long, conflicts_with = "example")]
+    binary: bool,
+
+    /// Run an embedded example program instead of a file
+    #[arg(short, long)]
+    example: Option<String>,
+
+    /// Radix for the assembled program and final machine state (hex or oct)
+    #[arg(long, default_value = "hex")]
+    radix: Radix,
+
+    /// Stop after this many clock cycles if the program hasn't halted
+    #[arg(long, default_value_t = DEFAULT_MAX_STEPS)]
+    max_steps: usize,
+
+    /// Show verbose output (including debug messages)
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    /// Assembly file to validate
+    file: String,
+
+    /// Show verbose output (including debug messages)
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+#[derive(Args)]
+struct DebugArgs {
+    /// Assembly file to debug
     file: String,
 
+    /// Clock cycles `continue` runs before giving up
+    #[arg(long, default_value_t = DEFAULT_MAX_STEPS)]
+    max_steps: usize,
+
     /// Show verbose output (including debug messages)
     #[arg(short, long)]
     verbose: bool,
 }
 
+#[derive(Args)]
+struct DisasmArgs {
+    /// Machine code file, as written by `assemble`
+    file: String,
+
+    /// Radix for the address and word columns (hex or oct)
+    #[arg(long, default_value = "hex")]
+    radix: Radix,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Workload to run (tight, copy or call); runs all of them if omitted
+    workload: Option<String>,
+
+    /// Number of instructions to execute per workload
+    #[arg(short = 'n', long, default_value_t = 1_000_000, value_parser = clap::value_parser!(u64).range(1..))]
+    instructions: u64,
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    match cli.command {
+        Command::Assemble(args) => assemble(args),
+        Command::Run(args) => run(args),
+        Command::Check(args) => check(args),
+        Command::Debug(args) => debug(args),
+        Command::Disasm(args) => disasm(args),
+        Command::Examples => {
+            list_examples();
+            Ok(())
+        }
+        Command::Bench(args) => bench(args),
+    }
+}
+
+fn assemble(args: AssembleArgs) -> Result<()> {
+    let path = Path::new(&args.file);
+
+    if !path.is_dir() {
+        let output = args.output.clone()
+            .unwrap_or_else(|| path.with_extension(args.format.extension()));
+        return assemble_file(path, &output, &args);
+    }
+
+    ensure!(args.output.is_none(), "--output can't be used when assembling a directory");
+
+    let mut files = Vec::new();
+    collect_sources(path, args.recursive, &mut files)?;
+
+    // foo.asm and foo.txt would both write foo.hex, so refuse before writing anything
+    let mut outputs: HashMap<PathBuf, &PathBuf> = HashMap::new();
+    for file in &files {
+        let output = file.with_extension(args.format.extension());
+        if let Some(other) = outputs.insert(output.clone(), file) {
+            bail!("{} and {} would both be written to {}", other.display(), file.display(), output.display());
+        }
+    }
+
+    let mut failed = 0;
+    for file in &files {
+        println!("=== {} ===", file.display());
+        if let Err(err) = assemble_file(file, &file.with_extension(args.format.extension()), &args) {
+            println!("ERR: {:#}", err);
+            failed += 1;
+        }
+    }
+
+    println!("\nAssembled {} of {} files", files.len() - failed, files.len());
+
+    if failed > 0 {
+        bail!("{} of {} files failed to assemble", failed, files.len());
+    }
+
+    Ok(())
+}
+
+fn assemble_file(file: &Path, output: &Path, args: &AssembleArgs) -> Result<()> {
+    let program = read_file(file)?;
+
+    let mut machine = Machine::new();
+    let messages = machine.prime(program);
+    print_messages(&messages, args.verbose);
+
+    if messages.has_errors() {
+        bail!("Assembly of {} failed", file.display());
+    }
+
+    let words = object::assembled_words(machine.get_assembled_program())?;
+
+    fs::write(output, object::encode(&words, args.format))
+        .with_context(|| format!("Failed to write {}", output.display()))?;
+
+    println!("Wrote {} words to {}", words.len(), output.display());
+
+    // Hex and bin images are zero-filled from address 0, so reruns start there
+    let origin = words.iter().position(Option::is_some).unwrap_or(0);
+    if origin != 0 && !args.format.keeps_origin() {
+        println!(
+            "Note: the program starts at {:03X}, but this format always loads from 0. Use --format ihex to keep the origin.",
+            origin,
+        );
+    }
+
+    Ok(())
+}
+
+/// Find assembly sources (.asm and .txt) in `dir`, in name order.
+fn collect_sources(dir: &Path, recursive: bool, files: &mut Vec<PathBuf>) -> Result<()> {
+    let mut entries = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read directory {}", dir.display()))?
+        .map(|entry| entry.and_then(|entry| Ok((entry.path(), entry.file_type()?))))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    for (path, file_type) in entries {
+        // file_type doesn't follow symlinks; linked directories are skipped so a
+        // loop can't assemble the same source through every nested path
+        if file_type.is_dir() {
+            if recursive {
+                collect_sources(&path, recursive, files)?;
+            }
+        } else if path.is_dir() {
+            continue;
+        } else if matches!(path.extension().and_then(|ext| ext.to_str()), Some("asm" | "txt")) {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    // Machine code is detected by extension, or forced with --binary (hex by default)
+    let binary_format = args.file.as_deref()
+        .and_then(|file| object::Format::from_path(Path::new(file)))
+        .or(args.binary.then_some(object::Format::Hex));
+
     // Read the assembly program
-    let program = read_file(&cli.file)?;
+    let program = match (&args.file, &args.example) {
+        (Some(file), _) => match binary_format {
+            Some(format) => object::to_source(&read_machine_code(file, format)?),
+            None => read_file(file)?,
+        },
+        (None, Some(name)) => {
+            let example = examples::find(name)
+                .with_context(|| format!("Unknown example '{}', see `mano examples`", name))?;
+            example.source.lines().map(String::from).collect()
+        }
+        (None, None) => unreachable!("clap requires a file or an example"),
+    };
 
     // Print source program
-    print_source_program(&program);
+    if binary_format.is_none() {
+        print_source_program(&program);
+    }
 
     // Create machine and prime it
     let mut machine = Machine::new();
     let messages = machine.prime(program);
 
     // Print assembly messages
-    print_messages(&messages, cli.verbose);
+    print_messages(&messages, args.verbose);
 
     // If there were errors, exit
     if messages.has_errors() {
@@ -44,20 +293,20 @@ fn main() -> Result<()> {
     }
 
     // Print assembled program
-    print_assembled_program(machine.get_assembled_program());
+    print_assembled_program(machine.get_assembled_program(), args.radix);
 
     // Run the program
     println!("=== Running Program ===\n");
 
     let mut step_count = 0;
-    let max_steps = 10000;
+    let max_steps = args.max_steps;
 
     loop {
         let mut messages = Messages::new();
         machine.tick(&mut messages);
 
         // Print messages from this tick
-        print_messages(&messages, cli.verbose);
+        print_messages(&messages, args.verbose);
 
         // Check for errors or halt
         if messages.has_errors() {
@@ -74,18 +323,92 @@ fn main() -> Result<()> {
 
         // Safety check to prevent infinite loops
         if step_count >= max_steps {
-            println!("\nProgram exceeded {} steps. Stopping to prevent infinite loop.", max_steps);
+            println!("\nProgram exceeded {} steps. Stopping to prevent infinite loop (raise with --max-steps).", max_steps);
             break;
         }
     }
 
     // Print final machine state
     let state = machine.get_state();
-    print_machine_state(&state);
+    print_machine_state(&state, args.radix);
 
     Ok(())
 }
 
+fn check(args: CheckArgs) -> Result<()> {
+    let program = read_file(&args.file)?;
+
+    let mut machine = Machine::new();
+    let messages = machine.prime(program);
+    print_messages(&messages, args.verbose);
+
+    if messages.has_errors() {
+        bail!("{} has assembly errors", args.file);
+    }
+
+    println!("{}: OK", args.file);
+
+    Ok(())
+}
+
+fn debug(args: DebugArgs) -> Result<()> {
+    let program = read_file(&args.file)?;
+
+    match debugger::Debugger::new(program, args.max_steps, args.verbose) {
+        Some(mut debugger) => debugger.run(),
+        None => bail!("Assembly of {} failed", args.file),
+    }
+}
+
+fn disasm(args: DisasmArgs) -> Result<()> {
+    let format = object::Format::from_path(Path::new(&args.file)).unwrap_or(object::Format::Hex);
+    let words = read_machine_code(&args.file, format)?;
+
+    print_disassembly(&words, args.radix);
+
+    Ok(())
+}
+
+fn list_examples() {
+    println!("Embedded example programs (run with `mano run --example <name>`):\n");
+    for example in examples::EXAMPLES {
+        println!("  {:12} {}", example.name, example.description);
+    }
+}
+
+fn bench(args: BenchArgs) -> Result<()> {
+    let workloads = match &args.workload {
+        Some(name) => vec![bench::find(name)
+            .with_context(|| format!("Unknown workload '{}'", name))?],
+        None => bench::WORKLOADS.iter().collect(),
+    };
+
+    println!("{:8} {:>12} {:>12} {:>10} {:>14}", "Workload", "Instructions", "Cycles", "Seconds", "Instr/s");
+    for workload in workloads {
+        let report = bench::run(workload, args.instructions)?;
+        println!(
+            "{:8} {:>12} {:>12} {:>10.3} {:>14.0}",
+            workload.name,
+            report.instructions,
+            report.cycles,
+            report.elapsed.as_secs_f64(),
+            report.instructions_per_second(),
+        );
+    }
+
+    println!();
+    for workload in bench::WORKLOADS {
+        println!("  {:8} {}", workload.name, workload.description);
+    }
+
+    Ok(())
+}
+
+fn read_machine_code(filename: &str, format: object::Format) -> Result<Vec<Option<u16>>> {
+    let bytes = fs::read(filename).with_context(|| format!("Failed to read {}", filename))?;
+    object::decode(&bytes, format)
+}
+
 fn read_file(filename: impl AsRef<Path>) -> Result<Vec<String>> {
     let file = File::open(filename)?;
     let buf = BufReader::new(file);
@@ -0,0 +1,330 @@
+use std::path::Path;
+
+use anyhow::{bail, ensure, Context, Result};
+use clap::ValueEnum;
+
+/// Words of Mano Machine memory; larger images can't be loaded.
+pub const MEMORY_WORDS: usize = 0x1000;
+
+/// Output formats for assembled machine code.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// Plain hex text, one word per line
+    Hex,
+    /// Raw little-endian words
+    Bin,
+    /// Intel HEX records with byte load addresses
+    Ihex,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Hex => "hex",
+            Format::Bin => "bin",
+            Format::Ihex => "ihx",
+        }
+    }
+
+    /// Whether the format records load addresses, rather than starting at 0.
+    pub fn keeps_origin(self) -> bool {
+        matches!(self, Format::Ihex)
+    }
+
+    /// Guess the format of a machine code file from its extension.
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "hex" => Some(Format::Hex),
+            "bin" => Some(Format::Bin),
+            "ihx" | "ihex" => Some(Format::Ihex),
+            _ => None,
+        }
+    }
+}
+
+/// Convert the assembler's hex strings into words, indexed by address.
+///
+/// Addresses the program doesn't occupy are `None`.
+pub fn assembled_words(program: &[String]) -> Result<Vec<Option<u16>>> {
+    program.iter()
+        .enumerate()
+        .map(|(addr, word)| {
+            if word.is_empty() {
+                Ok(None)
+            } else {
+                u16::from_str_radix(word, 16)
+                    .map(Some)
+                    .with_context(|| format!("Invalid assembled word '{}' at {:04X}", word, addr))
+            }
+        })
+        .collect()
+}
+
+/// Encode a program in the given format, filling unoccupied addresses with
+/// zero where the format has no way to skip them.
+pub fn encode(words: &[Option<u16>], format: Format) -> Vec<u8> {
+    let dense: Vec<u16> = words.iter().map(|word| word.unwrap_or(0)).collect();
+
+    match format {
+        Format::Hex => write_hex(&dense).into_bytes(),
+        Format::Bin => write_bin(&dense),
+        Format::Ihex => write_ihex(words).into_bytes(),
+    }
+}
+
+/// Plain hex text: one four-digit word per line, starting at address 0.
+pub fn write_hex(words: &[u16]) -> String {
+    words.iter()
+        .map(|word| format!("{:04X}\n", word))
+        .collect()
+}
+
+/// Raw image: each word as two little-endian bytes, starting at address 0.
+pub fn write_bin(words: &[u16]) -> Vec<u8> {
+    words.iter()
+        .flat_map(|word| word.to_le_bytes())
+        .collect()
+}
+
+/// Intel HEX: data records for each run of occupied words, at byte address
+/// `2 * word address`, followed by an end-of-file record.
+pub fn write_ihex(words: &[Option<u16>]) -> String {
+    let mut out = String::new();
+    let mut addr = 0;
+
+    while addr < words.len() {
+        if words[addr].is_none() {
+            addr += 1;
+            continue;
+        }
+
+        // Collect up to 16 bytes of consecutive words into one record
+        let start = addr;
+        let mut data = Vec::new();
+        while let Some(Some(word)) = words.get(addr) {
+            if data.len() == 16 {
+                break;
+            }
+            data.extend_from_slice(&word.to_le_bytes());
+            addr += 1;
+        }
+
+        out.push_str(&ihex_record((start * 2) as u16, 0x00, &data));
+    }
+
+    out.push_str(&ihex_record(0, 0x01, &[]));
+    out
+}
+
+fn ihex_record(address: u16, kind: u8, data: &[u8]) -> String {
+    let mut bytes = vec![data.len() as u8];
+    bytes.extend_from_slice(&address.to_be_bytes());
+    bytes.push(kind);
+    bytes.extend_from_slice(data);
+
+    let checksum = bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte)).wrapping_neg();
+    bytes.push(checksum);
+
+    let hex: String = bytes.iter().map(|byte| format!("{:02X}", byte)).collect();
+    format!(":{}\n", hex)
+}
+
+/// Parse plain hex text produced by [`write_hex`], ignoring blank lines.
+pub fn read_hex(text: &str) -> Result<Vec<u16>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            u16::from_str_radix(line.trim(), 16)
+                .with_context(|| format!("Invalid hex word '{}' on line {}", line.trim(), i + 1))
+        })
+        .collect()
+}
+
+/// Decode a machine code file, the inverse of [`encode`].
+///
+/// Fails if the image reaches past the end of memory.
+pub fn decode(bytes: &[u8], format: Format) -> Result<Vec<Option<u16>>> {
+    let words: Vec<Option<u16>> = match format {
+        Format::Hex => {
+            let text = std::str::from_utf8(bytes).context("Hex file is not valid text")?;
+            read_hex(text)?.into_iter().map(Some).collect()
+        }
+        Format::Bin => read_bin(bytes)?.into_iter().map(Some).collect(),
+        Format::Ihex => {
+            let text = std::str::from_utf8(bytes).context("Intel HEX file is not valid text")?;
+            read_ihex(text)?
+        }
+    };
+
+    ensure!(words.len() <= MEMORY_WORDS,
+        "Machine code spans {} words, more than the {} words of memory", words.len(), MEMORY_WORDS);
+
+    Ok(words)
+}
+
+/// Parse a raw little-endian image produced by [`write_bin`].
+pub fn read_bin(bytes: &[u8]) -> Result<Vec<u16>> {
+    ensure!(bytes.len().is_multiple_of(2), "Binary file has an odd number of bytes ({})", bytes.len());
+
+    Ok(bytes.chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect())
+}
+
+/// Parse Intel HEX data records into words at `byte address / 2`.
+pub fn read_ihex(text: &str) -> Result<Vec<Option<u16>>> {
+    let mut words = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let record = line.strip_prefix(':')
+            .with_context(|| format!("Record on line {} doesn't start with ':'", i + 1))?;
+        ensure!(record.len().is_multiple_of(2), "Record on line {} has an odd number of digits", i + 1);
+
+        let bytes = (0..record.len())
+            .step_by(2)
+            .map(|at| u8::from_str_radix(&record[at..at + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .with_context(|| format!("Invalid hex digits on line {}", i + 1))?;
+
+        ensure!(bytes.len() >= 5 && bytes.len() == bytes[0] as usize + 5,
+            "Record on line {} has the wrong length", i + 1);
+        ensure!(bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte)) == 0,
+            "Bad checksum on line {}", i + 1);
+
+        let address = u16::from_be_bytes([bytes[1], bytes[2]]) as usize;
+        let data = &bytes[4..bytes.len() - 1];
+
+        match bytes[3] {
+            0x00 => {
+                ensure!(address.is_multiple_of(2) && data.len().is_multiple_of(2),
+                    "Record on line {} isn't word aligned", i + 1);
+
+                for (offset, pair) in data.chunks_exact(2).enumerate() {
+                    let addr = address / 2 + offset;
+                    if words.len() <= addr {
+                        words.resize(addr + 1, None);
+                    }
+                    words[addr] = Some(u16::from_le_bytes([pair[0], pair[1]]));
+                }
+            }
+            0x01 => break,
+            kind => bail!("Unsupported record type {:02X} on line {}", kind, i + 1),
+        }
+    }
+
+    Ok(words)
+}
+
+/// Build an assembly listing that loads the given image, so machine code can
+/// be primed like any other program.
+///
+/// The listing starts at the first occupied address and fills any gaps with
+/// zero words.
+pub fn to_source(words: &[Option<u16>]) -> Vec<String> {
+    let origin = words.iter().position(Option::is_some).unwrap_or(0);
+    let end = words.iter().rposition(Option::is_some).map_or(origin, |last| last + 1);
+
+    let mut source = vec![format!("ORG {:X}", origin)];
+    source.extend(words[origin..end].iter().map(|word| format!("HEX {:04X}", word.unwrap_or(0))));
+    source.push("END".to_string());
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM: [Option<u16>; 5] = [Some(0x2004), None, Some(0x7001), None, Some(0x0053)];
+
+    fn error(result: Result<Vec<Option<u16>>>) -> String {
+        result.unwrap_err().to_string()
+    }
+
+    #[test]
+    fn hex_and_bin_round_trip_with_gaps_filled() {
+        let dense: Vec<Option<u16>> = PROGRAM.iter().map(|word| Some(word.unwrap_or(0))).collect();
+
+        for format in [Format::Hex, Format::Bin] {
+            let bytes = encode(&PROGRAM, format);
+            assert_eq!(decode(&bytes, format).unwrap(), dense);
+        }
+        assert_eq!(write_hex(&[0x2004, 0x7001]), "2004\n7001\n");
+        assert_eq!(write_bin(&[0x2004]), vec![0x04, 0x20]);
+    }
+
+    #[test]
+    fn ihex_round_trip_keeps_gaps_and_splits_long_runs() {
+        let mut words = vec![None; 0x100];
+        words.extend((0..10).map(Some));
+        words.extend_from_slice(&PROGRAM);
+
+        let text = write_ihex(&words);
+        assert_eq!(decode(text.as_bytes(), Format::Ihex).unwrap(), words);
+
+        // The 11-word run needs two records, then one per later word and EOF
+        assert_eq!(text.lines().count(), 5);
+        assert!(text.starts_with(":10020000"));
+        assert!(text.ends_with(":00000001FF\n"));
+    }
+
+    #[test]
+    fn ihex_rejects_a_bad_checksum() {
+        let record = ihex_record(0, 0x00, &[0x04, 0x20]);
+        assert_eq!(record, ":020000000420DA\n");
+
+        let corrupt = format!("{}00", &record[..record.len() - 3]);
+        assert_eq!(error(read_ihex(&corrupt)), "Bad checksum on line 1");
+    }
+
+    #[test]
+    fn ihex_rejects_malformed_records() {
+        assert_eq!(error(read_ihex(":0")), "Record on line 1 has an odd number of digits");
+        assert_eq!(error(read_ihex("0000000001FF")), "Record on line 1 doesn't start with ':'");
+        assert_eq!(error(read_ihex(":0200000001")), "Record on line 1 has the wrong length");
+        assert_eq!(error(read_ihex(&ihex_record(1, 0x00, &[0x04, 0x20]))),
+            "Record on line 1 isn't word aligned");
+        assert_eq!(error(read_ihex(&ihex_record(0, 0x00, &[0x04]))),
+            "Record on line 1 isn't word aligned");
+        assert_eq!(error(read_ihex(&ihex_record(0, 0x04, &[0x00, 0x00]))),
+            "Unsupported record type 04 on line 1");
+    }
+
+    #[test]
+    fn hex_and_bin_reject_malformed_input() {
+        assert_eq!(read_hex("2004\nXYZ\n").unwrap_err().to_string(), "Invalid hex word 'XYZ' on line 2");
+        assert_eq!(read_bin(&[0x04, 0x20, 0x01]).unwrap_err().to_string(),
+            "Binary file has an odd number of bytes (3)");
+    }
+
+    #[test]
+    fn decode_rejects_images_larger_than_memory() {
+        let full = write_hex(&[0; MEMORY_WORDS]);
+        assert_eq!(decode(full.as_bytes(), Format::Hex).unwrap().len(), MEMORY_WORDS);
+
+        let over = write_bin(&[0; MEMORY_WORDS + 1]);
+        assert_eq!(error(decode(&over, Format::Bin)),
+            "Machine code spans 4097 words, more than the 4096 words of memory");
+
+        // A single record past the end of memory is enough
+        let far = ihex_record((MEMORY_WORDS * 2) as u16, 0x00, &[0x01, 0x00]);
+        assert!(decode(far.as_bytes(), Format::Ihex).is_err());
+    }
+
+    #[test]
+    fn to_source_starts_at_the_first_occupied_word() {
+        let mut words = vec![None, None];
+        words.extend_from_slice(&PROGRAM);
+        words.push(None);
+
+        assert_eq!(to_source(&words), [
+            "ORG 2", "HEX 2004", "HEX 0000", "HEX 7001", "HEX 0000", "HEX 0053", "END",
+        ]);
+        assert_eq!(to_source(&[]), ["ORG 0", "END"]);
+    }
+}
@@ -1,5 +1,7 @@
 use mano_lib::message::{Messages, Level};
 use mano_lib::machine::MachineState;
+use mano_common::disasm::disassemble;
+use mano_common::radix::Radix;
 
 pub fn print_messages(messages: &Messages, verbose: bool) {
     for (level, msg) in &messages.entries {
@@ -21,57 +23,87 @@ pub fn print_source_program(program: &[String]) {
     println!();
 }
 
-pub fn print_assembled_program(program: &[String]) {
+pub fn print_assembled_program(program: &[String], radix: Radix) {
     println!("\n=== Assembled Program ===");
     if program.is_empty() {
         println!("(empty)");
     } else {
         for (addr, instruction) in program.iter().enumerate() {
             if !instruction.is_empty() {
-                println!("[{:04X}]: {}", addr, instruction);
+                // The assembler emits hex; re-radix it when it parses
+                let word = u16::from_str_radix(instruction, 16)
+                    .map(|word| radix.word(word))
+                    .unwrap_or_else(|_| instruction.clone());
+                println!("[{}]: {}", radix.address(addr), word);
             }
         }
     }
     println!();
 }
 
-pub fn print_machine_state(state: &MachineState) {
+pub fn print_machine_state(state: &MachineState, radix: Radix) {
+    let reg = |value: u16| format!("{}{}", radix.prefix(), radix.word(value));
+
     println!("\n=== Final Machine State ===");
     println!("┌─────────────────────────────────────┐");
     println!("│ CPU Registers                       │");
     println!("├─────────────────────────────────────┤");
-    println!("│ PC (Program Counter)    : 0x{:04X}    │", state.program_counter);
-    println!("│ AC (Accumulator)        : 0x{:04X}    │", state.accumulator);
-    println!("│ IR (Instruction Reg)    : 0x{:04X}    │", state.instruction_register);
-    println!("│ AR (Address Register)   : 0x{:04X}    │", state.address_register);
-    println!("│ DR (Data Register)      : 0x{:04X}    │", state.data_register);
-    println!("│ E  (Extend Register)    : 0x{:04X}    │", state.extend_register);
+    println!("│ PC (Program Counter)    : {:10}│", reg(state.program_counter));
+    println!("│ AC (Accumulator)        : {:10}│", reg(state.accumulator));
+    println!("│ IR (Instruction Reg)    : {:10}│", reg(state.instruction_register));
+    println!("│ AR (Address Register)   : {:10}│", reg(state.address_register));
+    println!("│ DR (Data Register)      : {:10}│", reg(state.data_register));
+    println!("│ E  (Extend Register)    : {:10}│", reg(state.extend_register));
     println!("│ SC (Sequence Counter)   : {:4}      │", state.sequence_counter);
     println!("├─────────────────────────────────────┤");
     println!("│ Status: {:27} │", if state.is_halted { " HALTED" } else { "RUNNING" });
     println!("└─────────────────────────────────────┘");
 
-    // Memory hex dump
+    // Memory dump, 16 words per row in hex or 8 in octal
+    let row_len = match radix {
+        Radix::Hex => 16,
+        Radix::Oct => 8,
+    };
+    let width = radix.word(0).len();
+
     println!("\n=== Memory Contents ===");
-    println!("Address   +0   +1   +2   +3   +4   +5   +6   +7   +8   +9   +A   +B   +C   +D   +E   +F");
-    println!("────────────────────────────────────────────────────────────────────────────────────────");
+    print!("Address  ");
+    for offset in 0..row_len {
+        print!(" {:<width$}", format!("+{:X}", offset));
+    }
+    println!();
+    println!("{}", "─".repeat(8 + row_len * (width + 1)));
 
     let memory = &state.memory_snapshot;
-    let total_lines = (memory.len() + 15) / 16; // Round up to next 16
+    let total_lines = memory.len().div_ceil(row_len);
 
     for line in 0..total_lines {
-        let base_addr = line * 16;
-        print!("{:04X}:    ", base_addr);
+        let base_addr = line * row_len;
+        print!("{}:    ", radix.address(base_addr));
 
-        for offset in 0..16 {
+        for offset in 0..row_len {
             let addr = base_addr + offset;
             if addr < memory.len() {
-                print!(" {:04X}", memory[addr]);
+                print!(" {}", radix.word(memory[addr]));
             } else {
-                print!("     ");
+                print!(" {:width$}", "");
             }
         }
         println!();
     }
     println!();
 }
+
+pub fn print_disassembly(words: &[Option<u16>], radix: Radix) {
+    println!("\n=== Disassembly ===");
+    if words.iter().all(Option::is_none) {
+        println!("(empty)");
+    } else {
+        for (addr, word) in words.iter().enumerate() {
+            if let Some(word) = word {
+                println!("[{}]: {}  {}", radix.address(addr), radix.word(*word), disassemble(*word));
+            }
+        }
+    }
+    println!();
+}
@@ -0,0 +1,63 @@
+const MRI: [&str; 7] = ["AND", "ADD", "LDA", "STA", "BUN", "BSA", "ISZ"];
+
+const RRI: [(u16, &str); 12] = [
+    (0x7800, "CLA"), (0x7400, "CLE"), (0x7200, "CMA"), (0x7100, "CME"),
+    (0x7080, "CIR"), (0x7040, "CIL"), (0x7020, "INC"), (0x7010, "SPA"),
+    (0x7008, "SNA"), (0x7004, "SZA"), (0x7002, "SZE"), (0x7001, "HLT"),
+];
+
+const IO: [(u16, &str); 6] = [
+    (0xF800, "INP"), (0xF400, "OUT"), (0xF200, "SKI"),
+    (0xF100, "SKO"), (0xF080, "ION"), (0xF040, "IOF"),
+];
+
+/// Decode a single instruction word into its assembly mnemonic.
+///
+/// Memory-reference operands are printed as 3-digit hex addresses, with the
+/// assembler's lowercase `i` marking indirect addressing.
+/// Words that don't match exactly one register-reference or I/O instruction
+/// are shown as `HEX` data.
+pub fn disassemble(word: u16) -> String {
+    let opcode = (word >> 12) & 0x7;
+    let indirect = word & 0x8000 != 0;
+
+    if opcode != 0x7 {
+        let mnemonic = MRI[opcode as usize];
+        let address = word & 0x0FFF;
+        return if indirect {
+            format!("{} {:03X} i", mnemonic, address)
+        } else {
+            format!("{} {:03X}", mnemonic, address)
+        };
+    }
+
+    let table: &[(u16, &str)] = if indirect { &IO } else { &RRI };
+    match table.iter().find(|(code, _)| *code == word) {
+        Some((_, mnemonic)) => mnemonic.to_string(),
+        None => format!("HEX {:04X}", word),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disassembles_memory_reference_words() {
+        assert_eq!(disassemble(0x2004), "LDA 004");
+        assert_eq!(disassemble(0x9ABC), "ADD ABC i");
+    }
+
+    #[test]
+    fn disassembles_register_reference_and_io_words() {
+        assert_eq!(disassemble(0x7800), "CLA");
+        assert_eq!(disassemble(0x7001), "HLT");
+        assert_eq!(disassemble(0xF400), "OUT");
+    }
+
+    #[test]
+    fn unknown_words_are_data() {
+        assert_eq!(disassemble(0x7003), "HEX 7003");
+        assert_eq!(disassemble(0xFFFF), "HEX FFFF");
+    }
+}
@@ -0,0 +1,5 @@
+//! Helpers shared by the Mano Machine frontends that don't need a `Machine`.
+
+pub mod disasm;
+pub mod limits;
+pub mod radix;
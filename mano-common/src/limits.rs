@@ -0,0 +1,4 @@
+/// Clock cycles a single run may take before a frontend stops it as a runaway
+/// loop. Each frontend lets the user override it (CLI and TUI `--max-steps`,
+/// the web toolbar's step limit).
+pub const DEFAULT_MAX_STEPS: usize = 10000;
@@ -0,0 +1,119 @@
+use std::str::FromStr;
+
+/// Number base for showing and entering addresses and machine words.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Radix {
+    #[default]
+    Hex,
+    Oct,
+}
+
+impl Radix {
+    pub fn toggle(self) -> Self {
+        match self {
+            Radix::Hex => Radix::Oct,
+            Radix::Oct => Radix::Hex,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Radix::Hex => "HEX",
+            Radix::Oct => "OCT",
+        }
+    }
+
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Radix::Hex => "0x",
+            Radix::Oct => "0o",
+        }
+    }
+
+    fn base(self) -> u32 {
+        match self {
+            Radix::Hex => 16,
+            Radix::Oct => 8,
+        }
+    }
+
+    /// `value` zero-padded to `hex_digits` in hex, or the same bit width in octal.
+    pub fn format(self, value: u16, hex_digits: usize) -> String {
+        match self {
+            Radix::Hex => format!("{:0width$X}", value, width = hex_digits),
+            Radix::Oct => format!("{:0width$o}", value, width = (hex_digits * 4).div_ceil(3)),
+        }
+    }
+
+    /// A 16-bit word, zero-padded to 4 hex or 6 octal digits.
+    pub fn word(self, value: u16) -> String {
+        self.format(value, 4)
+    }
+
+    /// A 16-bit word with its `0x`/`0o` prefix.
+    pub fn register(self, value: u16) -> String {
+        format!("{}{}", self.prefix(), self.word(value))
+    }
+
+    /// A 12-bit memory address, zero-padded to 4 digits in either base.
+    pub fn address(self, addr: usize) -> String {
+        match self {
+            Radix::Hex => format!("{:04X}", addr),
+            Radix::Oct => format!("{:04o}", addr),
+        }
+    }
+
+    pub fn is_digit(self, c: char) -> bool {
+        c.is_digit(self.base())
+    }
+
+    /// Parse a number typed by the user. An explicit `0x` or `0o` prefix
+    /// picks the base, otherwise it's read in this radix.
+    pub fn parse(self, text: &str) -> Option<u16> {
+        let text = text.trim();
+        let (radix, digits) = if let Some(digits) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+            (Radix::Hex, digits)
+        } else if let Some(digits) = text.strip_prefix("0o").or_else(|| text.strip_prefix("0O")) {
+            (Radix::Oct, digits)
+        } else {
+            (self, text)
+        };
+
+        u16::from_str_radix(digits, radix.base()).ok()
+    }
+}
+
+impl FromStr for Radix {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.to_ascii_lowercase().as_str() {
+            "hex" => Ok(Radix::Hex),
+            "oct" => Ok(Radix::Oct),
+            _ => Err(format!("unknown radix '{}', expected hex or oct", text)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_words_and_addresses() {
+        assert_eq!(Radix::Hex.word(0x01FF), "01FF");
+        assert_eq!(Radix::Oct.word(0o177777), "177777");
+        assert_eq!(Radix::Oct.address(0xFFF), "7777");
+        assert_eq!(Radix::Oct.register(8), "0o000010");
+        assert_eq!(Radix::Oct.format(0xFF, 2), "377");
+    }
+
+    #[test]
+    fn parses_in_own_radix_unless_prefixed() {
+        assert_eq!(Radix::Hex.parse("100"), Some(0x100));
+        assert_eq!(Radix::Oct.parse("100"), Some(0o100));
+        assert_eq!(Radix::Oct.parse("0x1F"), Some(0x1F));
+        assert_eq!(Radix::Hex.parse("0o17"), Some(0o17));
+        assert_eq!(Radix::Oct.parse("8"), None);
+    }
+}
@@ -0,0 +1,3 @@
+//! Reusable ratatui widgets for embedding Mano Machine simulator panes.
+
+pub mod widgets;
@@ -1,9 +1,10 @@
+use std::collections::BTreeSet;
 use std::fs::File;
 use std::io::{self, BufRead, BufReader};
 use std::path::Path;
 use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use clap::Parser;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
@@ -13,62 +14,166 @@ use crossterm::{
 use ratatui::{
     backend::{Backend, CrosstermBackend},
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
-    text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Table, Row, Cell},
+    widgets::{Block, Borders, Paragraph},
     Frame, Terminal,
 };
 
-use mano_lib::machine::{Machine, MachineState};
-use mano_lib::message::Level;
+use mano_lib::machine::Machine;
+use mano_common::limits::DEFAULT_MAX_STEPS;
+use mano_lib::message::{Level, Messages};
+use mano_tui::widgets::{
+    BreakpointsWidget, DisassemblyState, DisassemblyWidget, HexDumpState, HexDumpWidget, MessageLog,
+    MessagesWidget, Radix, RegistersWidget,
+};
+
+/// Words of memory shown in the memory pane.
+const MEMORY_WORDS: u16 = 0x1000;
+
+/// What the prompt in the controls pane is collecting input for.
+#[derive(Clone, Copy)]
+enum PromptKind {
+    Goto,
+    Breakpoint,
+    Command,
+}
+
+impl PromptKind {
+    fn title(self) -> &'static str {
+        match self {
+            PromptKind::Goto => "Goto",
+            PromptKind::Breakpoint => "Toggle breakpoint",
+            PromptKind::Command => "Command",
+        }
+    }
+
+    /// Address prompts take up to four digits in the display radix, or in the
+    /// base picked by a leading `0x`/`0o`. Commands take any text.
+    fn accepts(self, c: char, input: &str, radix: Radix) -> bool {
+        match self {
+            PromptKind::Goto | PromptKind::Breakpoint => {
+                if input == "0" && matches!(c, 'x' | 'X' | 'o' | 'O') {
+                    return true;
+                }
+
+                let (radix, digits) = match input.get(..2) {
+                    Some("0x" | "0X") => (Radix::Hex, &input[2..]),
+                    Some("0o" | "0O") => (Radix::Oct, &input[2..]),
+                    _ => (radix, input),
+                };
+                radix.is_digit(c) && digits.len() < 4
+            }
+            PromptKind::Command => input.len() < 64,
+        }
+    }
+}
+
+struct Prompt {
+    kind: PromptKind,
+    input: String,
+}
 
 #[derive(Parser)]
 #[command(about = "Mano Machine TUI")]
 struct Args {
     /// Assembly file to load
     file: String,
+
+    /// Clock cycles `continue` runs before giving up on reaching a breakpoint
+    #[arg(long, default_value_t = DEFAULT_MAX_STEPS)]
+    max_steps: usize,
 }
 
 struct App {
     machine: Machine,
-    messages: Vec<(Level, String)>,
+    messages: MessageLog,
+    disassembly: DisassemblyState,
+    memory: HexDumpState,
+    // Memory before the last step, to highlight what it wrote
+    previous_memory: Vec<u16>,
+    breakpoints: BTreeSet<u16>,
+    max_steps: usize,
+    prompt: Option<Prompt>,
+    radix: Radix,
     running: bool,
     auto_run: bool,
     last_tick: Instant,
 }
 
 impl App {
-    fn new(machine: Machine) -> Self {
+    fn new(machine: Machine, max_steps: usize) -> Self {
         Self {
             machine,
-            messages: Vec::new(),
+            // Keep only last 100 messages
+            messages: MessageLog::new(100),
+            disassembly: DisassemblyState::default(),
+            memory: HexDumpState::default(),
+            previous_memory: Vec::new(),
+            breakpoints: BTreeSet::new(),
+            max_steps,
+            prompt: None,
+            radix: Radix::default(),
             running: false,
             auto_run: false,
             last_tick: Instant::now(),
         }
     }
 
-    fn step(&mut self) {
-        use mano_lib::message::Messages;
+    /// Remember memory as it is now, so the next draw highlights what changed since.
+    fn snapshot_memory(&mut self) {
+        self.previous_memory = self.machine.load_memory_range(0, MEMORY_WORDS);
+    }
 
-        if !self.machine.is_halted() && self.machine.is_primed() {
-            let mut messages = Messages::new();
-            self.machine.tick(&mut messages);
-            for entry in messages.entries {
-                self.messages.push(entry);
-            }
+    /// Advance one clock cycle, returning `false` once the machine halts,
+    /// reports an error or reaches a breakpoint.
+    ///
+    /// Callers take the memory snapshot, so a run of steps copies memory once.
+    fn step(&mut self) -> bool {
+        if self.machine.is_halted() || !self.machine.is_primed() {
+            return false;
+        }
 
-            // Keep only last 100 messages
-            if self.messages.len() > 100 {
-                self.messages.drain(0..self.messages.len() - 100);
-            }
+        let mut messages = Messages::new();
+        self.machine.tick(&mut messages);
+
+        // Breakpoints only fire at instruction boundaries
+        let state = self.machine.get_state();
+        let at_breakpoint = state.sequence_counter == 0 && self.breakpoints.contains(&state.program_counter);
+        if at_breakpoint {
+            messages.entries.push((
+                Level::Info,
+                format!("Breakpoint at {}", self.radix.register(state.program_counter)),
+            ));
+        }
+
+        let stopped = messages.has_errors() || at_breakpoint || self.machine.is_halted();
+        self.messages.extend(messages);
+
+        if stopped {
+            self.auto_run = false;
+        }
+        !stopped
+    }
+
+    /// Run until a breakpoint, HLT or an error, highlighting everything written on the way.
+    fn continue_run(&mut self) {
+        self.snapshot_memory();
 
-            if self.machine.is_halted() {
-                self.auto_run = false;
+        let mut ticks = 0;
+        while self.step() {
+            ticks += 1;
+            if ticks >= self.max_steps {
+                self.log(Level::Info, format!("No breakpoint reached after {} steps", self.max_steps));
+                break;
             }
         }
     }
 
+    fn toggle_breakpoint(&mut self, addr: u16) {
+        if !self.breakpoints.remove(&addr) {
+            self.breakpoints.insert(addr);
+        }
+    }
+
     fn toggle_auto_run(&mut self) {
         self.auto_run = !self.auto_run;
         if self.auto_run {
@@ -77,18 +182,118 @@ impl App {
     }
 
     fn reset(&mut self) {
-        use mano_lib::message::Messages;
-
         let mut messages = Messages::new();
         self.machine.reset(&mut messages);
         self.messages.clear();
+        self.previous_memory.clear();
+        self.auto_run = false;
+    }
+
+    fn log(&mut self, level: Level, text: String) {
+        let mut messages = Messages::new();
+        messages.entries.push((level, text));
+        self.messages.extend(messages);
+    }
+
+    /// Assemble `filename` into a fresh machine, keeping the current one if it fails.
+    fn load(&mut self, filename: &str) -> Result<()> {
+        let program = read_file(filename).with_context(|| format!("Failed to read {}", filename))?;
+
+        let mut machine = Machine::new();
+        let mut messages = Messages::new();
+        machine.prime(program, &mut messages);
+
+        let failed = messages.has_errors();
+        self.messages.extend(messages);
+        if failed {
+            bail!("Assembly of {} failed", filename);
+        }
+
+        self.machine = machine;
+        self.disassembly = DisassemblyState::default();
+        self.previous_memory.clear();
         self.auto_run = false;
+        self.log(Level::Info, format!("Loaded {}", filename));
+        Ok(())
+    }
+
+    /// Run an ex-style command typed at the `:` prompt.
+    fn run_command(&mut self, line: &str) -> Result<()> {
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            return Ok(());
+        };
+        let arg = words.next();
+
+        match command {
+            "break" | "b" => {
+                let addr = parse_address(arg.context("Usage: break <addr>")?, self.radix)?;
+                self.breakpoints.insert(addr);
+                self.log(Level::Info, format!("Breakpoint set at {}", self.radix.register(addr)));
+            }
+            "delete" | "d" => match arg {
+                None => self.breakpoints.clear(),
+                Some(arg) => {
+                    let addr = parse_address(arg, self.radix)?;
+                    if !self.breakpoints.remove(&addr) {
+                        bail!("No breakpoint at {}", self.radix.register(addr));
+                    }
+                }
+            },
+            "mem" | "m" => {
+                let addr = parse_address(arg.context("Usage: mem <addr>")?, self.radix)?;
+                self.memory.goto(addr as usize);
+            }
+            "load" => self.load(arg.context("Usage: load <file>")?)?,
+            "reset" => self.reset(),
+            _ => bail!("Unknown command '{}' (break, delete, mem, load, reset)", command),
+        }
+        Ok(())
+    }
+
+    fn open_prompt(&mut self, kind: PromptKind) {
+        self.prompt = Some(Prompt { kind, input: String::new() });
+    }
+
+    /// Feed a key to the open prompt, acting on the address on Enter.
+    fn prompt_key(&mut self, code: KeyCode) {
+        let Some(prompt) = &mut self.prompt else {
+            return;
+        };
+
+        match code {
+            KeyCode::Char(c) if prompt.kind.accepts(c, &prompt.input, self.radix) => prompt.input.push(c),
+            KeyCode::Backspace => {
+                prompt.input.pop();
+            }
+            KeyCode::Enter => {
+                let Some(Prompt { kind, input }) = self.prompt.take() else {
+                    return;
+                };
+
+                if input.is_empty() {
+                    return;
+                }
+
+                let result = match kind {
+                    PromptKind::Goto => parse_address(&input, self.radix)
+                        .map(|addr| self.memory.goto(addr as usize)),
+                    PromptKind::Breakpoint => parse_address(&input, self.radix)
+                        .map(|addr| self.toggle_breakpoint(addr)),
+                    PromptKind::Command => self.run_command(&input),
+                };
+
+                if let Err(err) = result {
+                    self.log(Level::Error, format!("{:#}", err));
+                }
+            }
+            KeyCode::Esc => self.prompt = None,
+            _ => {}
+        }
     }
 }
 
 fn main() -> Result<()> {
-    use mano_lib::message::Messages;
-
     let args = Args::parse();
 
     // Load and prime the machine
@@ -115,7 +320,7 @@ fn main() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Create app and run
-    let mut app = App::new(machine);
+    let mut app = App::new(machine, args.max_steps);
     let res = run_app(&mut terminal, &mut app);
 
     // Restore terminal
@@ -140,6 +345,7 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
 
         // Auto-run logic
         if app.auto_run && app.last_tick.elapsed() >= Duration::from_millis(100) {
+            app.snapshot_memory();
             app.step();
             app.last_tick = Instant::now();
         }
@@ -147,12 +353,25 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
         // Handle input
         if event::poll(Duration::from_millis(50))? {
             if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
+                if key.kind == KeyEventKind::Press && app.prompt.is_some() {
+                    app.prompt_key(key.code);
+                } else if key.kind == KeyEventKind::Press {
                     match key.code {
                         KeyCode::Char('q') => return Ok(()),
-                        KeyCode::Char('s') => app.step(),
+                        KeyCode::Char('s') => {
+                            app.snapshot_memory();
+                            app.step();
+                        }
+                        KeyCode::Char('c') => app.continue_run(),
                         KeyCode::Char('r') => app.toggle_auto_run(),
                         KeyCode::Char('x') => app.reset(),
+                        KeyCode::Char('o') => app.radix = app.radix.toggle(),
+                        KeyCode::Char('g') => app.open_prompt(PromptKind::Goto),
+                        KeyCode::Char('b') => app.open_prompt(PromptKind::Breakpoint),
+                        KeyCode::Char('B') => app.breakpoints.clear(),
+                        KeyCode::Char(':') => app.open_prompt(PromptKind::Command),
+                        KeyCode::PageUp => app.memory.page_up(),
+                        KeyCode::PageDown => app.memory.page_down(),
                         _ => {}
                     }
                 }
@@ -161,7 +380,7 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
     }
 }
 
-fn ui(f: &mut Frame, app: &App) {
+fn ui(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
@@ -169,118 +388,90 @@ fn ui(f: &mut Frame, app: &App) {
 
     let left_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(8), Constraint::Min(0)].as_ref())
+        .constraints([Constraint::Length(9), Constraint::Min(0)].as_ref())
         .split(chunks[0]);
 
-    // Registers panel
-    draw_registers(f, left_chunks[0], &app.machine.get_state());
+    let code_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(14)].as_ref())
+        .split(left_chunks[1]);
 
-    // Memory panel
-    draw_memory(f, left_chunks[1], &app.machine);
+    let right_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(60), Constraint::Min(0), Constraint::Length(3)].as_ref())
+        .split(chunks[1]);
 
-    // Messages panel
-    draw_messages(f, chunks[1], &app.messages, app.auto_run, app.machine.is_halted());
-}
+    let state = app.machine.get_state();
 
-fn draw_registers(f: &mut Frame, area: Rect, state: &MachineState) {
-    let rows = vec![
-        Row::new(vec![Cell::from("PC"), Cell::from(format!("0x{:04X}", state.program_counter))]),
-        Row::new(vec![Cell::from("AC"), Cell::from(format!("0x{:04X}", state.accumulator))]),
-        Row::new(vec![Cell::from("IR"), Cell::from(format!("0x{:04X}", state.instruction_register))]),
-        Row::new(vec![Cell::from("AR"), Cell::from(format!("0x{:04X}", state.address_register))]),
-        Row::new(vec![Cell::from("DR"), Cell::from(format!("0x{:04X}", state.data_register))]),
-        Row::new(vec![Cell::from("E"), Cell::from(format!("0x{:04X}", state.extend_register))]),
-        Row::new(vec![Cell::from("SC"), Cell::from(format!("{}", state.sequence_counter))]),
-    ];
-
-    let status_style = if state.is_halted {
-        Style::default().fg(Color::Red)
-    } else if state.is_running {
-        Style::default().fg(Color::Green)
-    } else {
-        Style::default().fg(Color::Yellow)
-    };
+    // Registers panel
+    f.render_widget(RegistersWidget::new(&state).radix(app.radix), left_chunks[0]);
 
-    let status = if state.is_halted {
-        "HALTED"
-    } else if state.is_running {
-        "RUNNING"
-    } else {
-        "READY"
-    };
+    // Disassembly panel
+    let disassembly = DisassemblyWidget::new(app.machine.get_assembled_program(), state.program_counter)
+        .breakpoints(&app.breakpoints)
+        .radix(app.radix);
+    f.render_stateful_widget(disassembly, code_chunks[0], &mut app.disassembly);
 
-    let table = Table::new(rows)
-        .block(Block::default()
-            .borders(Borders::ALL)
-            .title(format!("Registers [{}]", status))
-            .title_style(status_style))
-        .widths(&[Constraint::Length(4), Constraint::Length(8)]);
+    // Breakpoints panel
+    let breakpoints = BreakpointsWidget::new(&app.breakpoints, state.program_counter).radix(app.radix);
+    f.render_widget(breakpoints, code_chunks[1]);
 
-    f.render_widget(table, area);
-}
+    // Memory panel
+    let memory = app.machine.load_memory_range(0, MEMORY_WORDS);
+    let hexdump = HexDumpWidget::new(&memory)
+        .previous(&app.previous_memory)
+        .registers(state.program_counter, state.address_register)
+        .radix(app.radix);
+    f.render_stateful_widget(hexdump, right_chunks[0], &mut app.memory);
 
-fn draw_memory(f: &mut Frame, area: Rect, machine: &Machine) {
-    let memory = machine.load_memory_range(0, 16);
-    let mut items = Vec::new();
-
-    for (addr, value) in memory.iter().enumerate() {
-        if *value != 0 {
-            let line = Line::from(vec![
-                Span::raw(format!("[{:04X}]: ", addr)),
-                Span::styled(format!("{:04X}", value), Style::default().fg(Color::Cyan)),
-            ]);
-            items.push(ListItem::new(line));
-        }
-    }
+    // Messages panel
+    f.render_widget(MessagesWidget::new(&app.messages), right_chunks[1]);
 
-    if items.is_empty() {
-        items.push(ListItem::new("No data in memory"));
+    match &app.prompt {
+        Some(prompt) => draw_prompt(f, right_chunks[2], prompt, app.radix),
+        None => draw_controls(f, right_chunks[2], app.auto_run, app.machine.is_halted(), app.radix),
     }
-
-    let list = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title("Memory"));
-
-    f.render_widget(list, area);
 }
 
-fn draw_messages(f: &mut Frame, area: Rect, messages: &[(Level, String)], auto_run: bool, is_halted: bool) {
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
-        .split(area);
-
-    // Messages
-    let items: Vec<ListItem> = messages
-        .iter()
-        .rev()
-        .take(chunks[0].height as usize - 2) // Account for borders
-        .map(|(level, msg)| {
-            let style = match level {
-                Level::Error => Style::default().fg(Color::Red),
-                Level::Info => Style::default().fg(Color::Green),
-                Level::Debug => Style::default().fg(Color::Gray),
-            };
-            ListItem::new(Line::from(Span::styled(msg.clone(), style)))
-        })
-        .collect();
-
-    let list = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title("Messages"));
-
-    f.render_widget(list, chunks[0]);
-
-    // Controls
+fn draw_controls(f: &mut Frame, area: Rect, auto_run: bool, is_halted: bool, radix: Radix) {
     let auto_status = if auto_run { "ON" } else { "OFF" };
     let help_text = if is_halted {
-        format!("HALTED | [Q]uit [X]reset | Auto: {}", auto_status)
+        format!("HALTED | [Q]uit [X]reset [B]reak [O]ctal [G]oto [:]cmd | Auto: {} | {}", auto_status, radix.name())
     } else {
-        format!("[S]tep [R]un [Q]uit [X]reset | Auto: {}", auto_status)
+        format!("[S]tep [R]un [C]ont [B]reak [Q]uit [X]reset [O]ctal [G]oto [:]cmd | Auto: {} | {}", auto_status, radix.name())
     };
 
     let controls = Paragraph::new(help_text)
         .block(Block::default().borders(Borders::ALL).title("Controls"));
 
-    f.render_widget(controls, chunks[1]);
+    f.render_widget(controls, area);
+}
+
+fn draw_prompt(f: &mut Frame, area: Rect, prompt: &Prompt, radix: Radix) {
+    let text = match prompt.kind {
+        PromptKind::Command => format!(":{}_", prompt.input),
+        _ => format!(
+            "Address ({}): {}_ | [Enter] ok [Esc] cancel",
+            radix.name().to_lowercase(),
+            prompt.input,
+        ),
+    };
+
+    let widget = Paragraph::new(text)
+        .block(Block::default().borders(Borders::ALL).title(prompt.kind.title()));
+
+    f.render_widget(widget, area);
+}
+
+/// Parse an address in the display radix, or in the base given by a `0x`/`0o` prefix.
+fn parse_address(text: &str, radix: Radix) -> Result<u16> {
+    let addr = radix.parse(text)
+        .with_context(|| format!("Invalid {} address '{}'", radix.name().to_lowercase(), text))?;
+
+    if addr >= MEMORY_WORDS {
+        bail!("Address {} is outside memory", text);
+    }
+    Ok(addr)
 }
 
 fn read_file(filename: impl AsRef<Path>) -> Result<Vec<String>> {
@@ -288,4 +479,4 @@ fn read_file(filename: impl AsRef<Path>) -> Result<Vec<String>> {
     let buf = BufReader::new(file);
     let lines: Result<Vec<String>, _> = buf.lines().collect();
     Ok(lines?)
-}
\ No newline at end of file
+}
\ No newline at end of file
@@ -0,0 +1,76 @@
+use std::collections::BTreeSet;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, Widget},
+};
+
+use super::Radix;
+
+/// List of breakpoint addresses, with the one at PC highlighted.
+pub struct BreakpointsWidget<'a> {
+    breakpoints: &'a BTreeSet<u16>,
+    program_counter: u16,
+    radix: Radix,
+}
+
+impl<'a> BreakpointsWidget<'a> {
+    pub fn new(breakpoints: &'a BTreeSet<u16>, program_counter: u16) -> Self {
+        Self {
+            breakpoints,
+            program_counter,
+            radix: Radix::default(),
+        }
+    }
+
+    pub fn radix(mut self, radix: Radix) -> Self {
+        self.radix = radix;
+        self
+    }
+}
+
+impl Widget for BreakpointsWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut items: Vec<ListItem> = self.breakpoints
+            .iter()
+            .map(|addr| {
+                let style = if *addr == self.program_counter {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Red)
+                };
+                ListItem::new(format!("* {}", self.radix.address(*addr as usize))).style(style)
+            })
+            .collect();
+
+        if items.is_empty() {
+            items.push(ListItem::new("None"));
+        }
+
+        List::new(items)
+            .block(Block::default().borders(Borders::ALL).title("Breakpoints"))
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_addresses_in_order_with_pc_highlighted() {
+        let breakpoints = BTreeSet::from([0x10, 0x04]);
+
+        let area = Rect::new(0, 0, 16, 4);
+        let mut buf = Buffer::empty(area);
+        BreakpointsWidget::new(&breakpoints, 0x10).render(area, &mut buf);
+
+        let row = |y| (1..15).map(|x| buf.get(x, y).symbol.clone()).collect::<String>();
+        assert_eq!(row(1).trim_end(), "* 0004");
+        assert_eq!(row(2).trim_end(), "* 0010");
+        assert_eq!(buf.get(1, 1).fg, Color::Red);
+        assert_eq!(buf.get(1, 2).fg, Color::Yellow);
+    }
+}
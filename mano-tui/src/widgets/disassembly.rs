@@ -0,0 +1,130 @@
+use std::collections::BTreeSet;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, StatefulWidget, Widget},
+};
+
+use mano_common::disasm::disassemble;
+
+use super::Radix;
+
+/// Scroll position of a [`DisassemblyWidget`], kept between frames.
+#[derive(Default)]
+pub struct DisassemblyState {
+    offset: usize,
+}
+
+/// Decoded listing of the assembled program with the word at PC highlighted.
+pub struct DisassemblyWidget<'a> {
+    program: &'a [String],
+    program_counter: u16,
+    breakpoints: Option<&'a BTreeSet<u16>>,
+    radix: Radix,
+}
+
+impl<'a> DisassemblyWidget<'a> {
+    /// `program` is the assembler output, one hex word per address.
+    pub fn new(program: &'a [String], program_counter: u16) -> Self {
+        Self {
+            program,
+            program_counter,
+            breakpoints: None,
+            radix: Radix::default(),
+        }
+    }
+
+    /// Mark these addresses with `*` in the gutter.
+    pub fn breakpoints(mut self, breakpoints: &'a BTreeSet<u16>) -> Self {
+        self.breakpoints = Some(breakpoints);
+        self
+    }
+
+    /// Radix for the address and word columns; operands stay in assembler hex.
+    pub fn radix(mut self, radix: Radix) -> Self {
+        self.radix = radix;
+        self
+    }
+}
+
+impl StatefulWidget for DisassemblyWidget<'_> {
+    type State = DisassemblyState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let visible = area.height.saturating_sub(2) as usize; // Account for borders
+        let pc = self.program_counter as usize;
+
+        // Keep the PC row on screen
+        if pc < state.offset {
+            state.offset = pc;
+        } else if visible > 0 && pc >= state.offset + visible {
+            state.offset = pc + 1 - visible;
+        }
+
+        let mut items: Vec<ListItem> = self.program
+            .iter()
+            .enumerate()
+            .skip(state.offset)
+            .take(visible)
+            .filter_map(|(addr, hex_str)| {
+                let word = u16::from_str_radix(hex_str, 16).ok()?;
+                let style = if addr == pc {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                let is_breakpoint = self.breakpoints
+                    .is_some_and(|breakpoints| breakpoints.contains(&(addr as u16)));
+                let line = Line::from(vec![
+                    Span::styled(if addr == pc { ">" } else { " " }, style),
+                    Span::styled(if is_breakpoint { "*" } else { " " }, Style::default().fg(Color::Red)),
+                    Span::raw(format!("[{}] ", self.radix.address(addr))),
+                    Span::styled(format!("{}  ", self.radix.word(word)), Style::default().fg(Color::Cyan)),
+                    Span::styled(disassemble(word), style),
+                ]);
+                Some(ListItem::new(line))
+            })
+            .collect();
+
+        if items.is_empty() {
+            items.push(ListItem::new("No assembled program"));
+        }
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title("Disassembly"));
+
+        Widget::render(list, area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(program: &[String], pc: u16, state: &mut DisassemblyState) -> Buffer {
+        // Four visible rows inside the borders
+        let area = Rect::new(0, 0, 30, 6);
+        let mut buf = Buffer::empty(area);
+        DisassemblyWidget::new(program, pc).render(area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn state_scrolls_to_keep_pc_visible() {
+        let program = vec!["7020".to_string(); 20];
+        let mut state = DisassemblyState::default();
+
+        render(&program, 2, &mut state);
+        assert_eq!(state.offset, 0);
+
+        let buf = render(&program, 10, &mut state);
+        assert_eq!(state.offset, 7);
+        assert_eq!(buf.get(1, 4).symbol, ">");
+
+        render(&program, 3, &mut state);
+        assert_eq!(state.offset, 3);
+    }
+}
@@ -0,0 +1,214 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, StatefulWidget, Widget},
+};
+
+use super::Radix;
+
+/// Scroll position of a [`HexDumpWidget`], kept between frames.
+#[derive(Default)]
+pub struct HexDumpState {
+    top: usize,
+    page: usize,
+}
+
+impl HexDumpState {
+    pub fn page_up(&mut self) {
+        self.top = self.top.saturating_sub(self.page);
+    }
+
+    pub fn page_down(&mut self) {
+        self.top += self.page;
+    }
+
+    /// Scroll so the row holding `addr` is at the top.
+    pub fn goto(&mut self, addr: usize) {
+        self.top = addr;
+    }
+}
+
+/// Paged dump of all of memory, highlighting PC, AR and recently written words.
+pub struct HexDumpWidget<'a> {
+    memory: &'a [u16],
+    previous: &'a [u16],
+    program_counter: u16,
+    address_register: u16,
+    radix: Radix,
+}
+
+impl<'a> HexDumpWidget<'a> {
+    /// `memory` starts at address 0.
+    pub fn new(memory: &'a [u16]) -> Self {
+        Self {
+            memory,
+            previous: &[],
+            program_counter: 0,
+            address_register: 0,
+            radix: Radix::default(),
+        }
+    }
+
+    /// Memory as it was before the last step; words that differ are highlighted.
+    pub fn previous(mut self, previous: &'a [u16]) -> Self {
+        self.previous = previous;
+        self
+    }
+
+    pub fn registers(mut self, program_counter: u16, address_register: u16) -> Self {
+        self.program_counter = program_counter;
+        self.address_register = address_register;
+        self
+    }
+
+    pub fn radix(mut self, radix: Radix) -> Self {
+        self.radix = radix;
+        self
+    }
+}
+
+impl StatefulWidget for HexDumpWidget<'_> {
+    type State = HexDumpState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let visible = area.height.saturating_sub(2) as usize; // Account for borders
+        let inner_width = area.width.saturating_sub(2) as usize;
+
+        // Fit as many words per row as the pane allows, in powers of two up to 16
+        let address_width = self.radix.address(0).len() + 1;
+        let word_width = self.radix.word(0).len() + 1;
+        let mut row_len = 16;
+        while row_len > 1 && address_width + row_len * word_width > inner_width {
+            row_len /= 2;
+        }
+
+        // Keep the top row aligned and the last page full
+        let rows = self.memory.len().div_ceil(row_len);
+        let top_row = (state.top / row_len).min(rows.saturating_sub(visible));
+        state.top = top_row * row_len;
+        state.page = visible.max(1) * row_len;
+
+        let pc = self.program_counter as usize;
+        let ar = self.address_register as usize;
+
+        let lines: Vec<Line> = (top_row..rows)
+            .take(visible)
+            .map(|row| {
+                let base = row * row_len;
+                let mut spans = vec![Span::raw(format!("{}:", self.radix.address(base)))];
+
+                for (addr, value) in self.memory.iter().enumerate().skip(base).take(row_len) {
+                    let mut style = Style::default();
+                    if self.previous.get(addr).is_some_and(|old| old != value) {
+                        style = style.fg(Color::Red).add_modifier(Modifier::BOLD);
+                    } else if *value != 0 {
+                        style = style.fg(Color::Cyan);
+                    }
+                    if addr == ar {
+                        style = style.add_modifier(Modifier::UNDERLINED);
+                    }
+                    if addr == pc {
+                        style = style.fg(Color::Black).bg(Color::Yellow);
+                    }
+
+                    spans.push(Span::raw(" "));
+                    spans.push(Span::styled(self.radix.word(*value), style));
+                }
+
+                Line::from(spans)
+            })
+            .collect();
+
+        let last = (state.top + state.page).min(self.memory.len()).saturating_sub(1);
+        let title = format!("Memory {}-{}", self.radix.address(state.top), self.radix.address(last));
+
+        Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(widget: HexDumpWidget, width: u16, state: &mut HexDumpState) -> Buffer {
+        // Four visible rows inside the borders
+        let area = Rect::new(0, 0, width, 6);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf, state);
+        buf
+    }
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        let text: String = (1..buf.area.width - 1).map(|x| buf.get(x, y).symbol.clone()).collect();
+        text.trim_end().to_string()
+    }
+
+    #[test]
+    fn row_length_fits_the_pane() {
+        let memory = vec![0; 64];
+        let mut state = HexDumpState::default();
+
+        let buf = render(HexDumpWidget::new(&memory), 50, &mut state);
+        assert!(row(&buf, 2).starts_with("0008: 0000"));
+
+        let buf = render(HexDumpWidget::new(&memory).radix(Radix::Oct), 50, &mut state);
+        assert!(row(&buf, 2).starts_with("0004: 000000"));
+    }
+
+    #[test]
+    fn goto_aligns_the_top_row_and_keeps_the_last_page_full() {
+        let memory = vec![0; 64];
+        let mut state = HexDumpState::default();
+
+        state.goto(13);
+        let buf = render(HexDumpWidget::new(&memory), 50, &mut state);
+        assert_eq!(state.top, 8);
+        assert!(row(&buf, 0).starts_with("Memory 0008-0027"));
+
+        state.goto(60);
+        let buf = render(HexDumpWidget::new(&memory), 50, &mut state);
+        assert_eq!(state.top, 32);
+        assert!(row(&buf, 0).starts_with("Memory 0020-003F"));
+        assert!(row(&buf, 4).starts_with("0038:"));
+    }
+
+    #[test]
+    fn paging_moves_by_a_screen_and_stops_at_the_ends() {
+        let memory = vec![0; 64];
+        let mut state = HexDumpState::default();
+        render(HexDumpWidget::new(&memory), 50, &mut state);
+
+        state.page_down();
+        render(HexDumpWidget::new(&memory), 50, &mut state);
+        assert_eq!(state.top, 32);
+
+        state.page_down();
+        render(HexDumpWidget::new(&memory), 50, &mut state);
+        assert_eq!(state.top, 32);
+
+        state.page_up();
+        state.page_up();
+        render(HexDumpWidget::new(&memory), 50, &mut state);
+        assert_eq!(state.top, 0);
+    }
+
+    #[test]
+    fn highlights_pc_and_changed_words() {
+        let memory = [0x2004, 0x7001, 0x0053, 0];
+        let previous = [0x2004, 0x7001, 0, 0];
+        let mut state = HexDumpState::default();
+
+        let widget = HexDumpWidget::new(&memory).previous(&previous).registers(1, 3);
+        let buf = render(widget, 30, &mut state);
+
+        // Words start after "0000:" and are five columns apart
+        assert_eq!(row(&buf, 1), "0000: 2004 7001 0053 0000");
+        assert_eq!(buf.get(12, 1).bg, Color::Yellow);
+        assert_eq!(buf.get(17, 1).fg, Color::Red);
+        assert!(buf.get(22, 1).modifier.contains(Modifier::UNDERLINED));
+    }
+}
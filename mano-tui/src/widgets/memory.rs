@@ -0,0 +1,81 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Widget},
+};
+
+use super::Radix;
+
+/// List of the non-zero words in a window of memory.
+pub struct MemoryWidget<'a> {
+    words: &'a [u16],
+    base: u16,
+    radix: Radix,
+}
+
+impl<'a> MemoryWidget<'a> {
+    /// `words` is a slice of memory whose first element lives at address `base`.
+    pub fn new(words: &'a [u16], base: u16) -> Self {
+        Self { words, base, radix: Radix::default() }
+    }
+
+    pub fn radix(mut self, radix: Radix) -> Self {
+        self.radix = radix;
+        self
+    }
+}
+
+impl Widget for MemoryWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut items = Vec::new();
+
+        for (offset, value) in self.words.iter().enumerate() {
+            if *value != 0 {
+                let line = Line::from(vec![
+                    Span::raw(format!("[{}]: ", self.radix.address(self.base as usize + offset))),
+                    Span::styled(self.radix.word(*value), Style::default().fg(Color::Cyan)),
+                ]);
+                items.push(ListItem::new(line));
+            }
+        }
+
+        if items.is_empty() {
+            items.push(ListItem::new("No data in memory"));
+        }
+
+        List::new(items)
+            .block(Block::default().borders(Borders::ALL).title("Memory"))
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(widget: MemoryWidget) -> Vec<String> {
+        let area = Rect::new(0, 0, 20, 5);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+
+        (1..area.height - 1)
+            .map(|y| (1..area.width - 1).map(|x| buf.get(x, y).symbol.clone()).collect::<String>())
+            .map(|row| row.trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn lists_non_zero_words_from_base() {
+        let words = [0, 0x0005, 0, 0x001F];
+        assert_eq!(render(MemoryWidget::new(&words, 0x100)), ["[0101]: 0005", "[0103]: 001F", ""]);
+        assert_eq!(render(MemoryWidget::new(&words, 8).radix(Radix::Oct)),
+            ["[0011]: 000005", "[0013]: 000037", ""]);
+    }
+
+    #[test]
+    fn empty_memory_says_so() {
+        assert_eq!(render(MemoryWidget::new(&[0; 4], 0))[0], "No data in memory");
+    }
+}
@@ -0,0 +1,108 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Widget},
+};
+
+use mano_lib::message::{Level, Messages};
+
+/// Bounded log of machine messages, oldest entries are dropped first.
+pub struct MessageLog {
+    entries: Vec<(Level, String)>,
+    capacity: usize,
+}
+
+impl MessageLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Vec::new(),
+            capacity,
+        }
+    }
+
+    pub fn extend(&mut self, messages: Messages) {
+        self.entries.extend(messages.entries);
+
+        if self.entries.len() > self.capacity {
+            self.entries.drain(0..self.entries.len() - self.capacity);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn entries(&self) -> &[(Level, String)] {
+        &self.entries
+    }
+}
+
+/// Newest-first view of a [`MessageLog`], coloured by level.
+pub struct MessagesWidget<'a> {
+    log: &'a MessageLog,
+}
+
+impl<'a> MessagesWidget<'a> {
+    pub fn new(log: &'a MessageLog) -> Self {
+        Self { log }
+    }
+}
+
+impl Widget for MessagesWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let items: Vec<ListItem> = self.log.entries()
+            .iter()
+            .rev()
+            .take(area.height.saturating_sub(2) as usize) // Account for borders
+            .map(|(level, msg)| {
+                let style = match level {
+                    Level::Error => Style::default().fg(Color::Red),
+                    Level::Info => Style::default().fg(Color::Green),
+                    Level::Debug => Style::default().fg(Color::Gray),
+                };
+                ListItem::new(Line::from(Span::styled(msg.clone(), style)))
+            })
+            .collect();
+
+        List::new(items)
+            .block(Block::default().borders(Borders::ALL).title("Messages"))
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(texts: &[&str]) -> Messages {
+        let mut messages = Messages::new();
+        messages.entries.extend(texts.iter().map(|text| (Level::Info, text.to_string())));
+        messages
+    }
+
+    #[test]
+    fn extend_drops_oldest_entries_at_capacity() {
+        let mut log = MessageLog::new(3);
+        log.extend(messages(&["a", "b"]));
+        log.extend(messages(&["c", "d", "e"]));
+
+        let texts: Vec<&str> = log.entries().iter().map(|(_, text)| text.as_str()).collect();
+        assert_eq!(texts, ["c", "d", "e"]);
+    }
+
+    #[test]
+    fn widget_renders_newest_first() {
+        let mut log = MessageLog::new(10);
+        log.extend(messages(&["first", "second"]));
+
+        let area = Rect::new(0, 0, 20, 4);
+        let mut buf = Buffer::empty(area);
+        MessagesWidget::new(&log).render(area, &mut buf);
+
+        let row = |y| (1..19).map(|x| buf.get(x, y).symbol.clone()).collect::<String>();
+        assert_eq!(row(1).trim_end(), "second");
+        assert_eq!(row(2).trim_end(), "first");
+    }
+}
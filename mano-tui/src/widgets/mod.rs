@@ -0,0 +1,15 @@
+mod breakpoints;
+mod disassembly;
+mod hexdump;
+mod memory;
+mod messages;
+mod registers;
+
+pub use breakpoints::BreakpointsWidget;
+pub use disassembly::{DisassemblyState, DisassemblyWidget};
+pub use hexdump::{HexDumpState, HexDumpWidget};
+pub use memory::MemoryWidget;
+pub use messages::{MessageLog, MessagesWidget};
+pub use registers::RegistersWidget;
+
+pub use mano_common::{disasm::disassemble, radix::Radix};
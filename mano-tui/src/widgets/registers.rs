@@ -0,0 +1,120 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Cell, Row, Table, Widget},
+};
+
+use mano_lib::machine::MachineState;
+
+use super::Radix;
+
+/// CPU register table with a coloured run status in the title.
+pub struct RegistersWidget<'a> {
+    state: &'a MachineState,
+    radix: Radix,
+}
+
+impl<'a> RegistersWidget<'a> {
+    pub fn new(state: &'a MachineState) -> Self {
+        Self { state, radix: Radix::default() }
+    }
+
+    pub fn radix(mut self, radix: Radix) -> Self {
+        self.radix = radix;
+        self
+    }
+}
+
+impl Widget for RegistersWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let state = self.state;
+        let radix = self.radix;
+        let rows = vec![
+            Row::new(vec![Cell::from("PC"), Cell::from(radix.register(state.program_counter))]),
+            Row::new(vec![Cell::from("AC"), Cell::from(radix.register(state.accumulator))]),
+            Row::new(vec![Cell::from("IR"), Cell::from(radix.register(state.instruction_register))]),
+            Row::new(vec![Cell::from("AR"), Cell::from(radix.register(state.address_register))]),
+            Row::new(vec![Cell::from("DR"), Cell::from(radix.register(state.data_register))]),
+            Row::new(vec![Cell::from("E"), Cell::from(radix.register(state.extend_register))]),
+            Row::new(vec![Cell::from("SC"), Cell::from(format!("{}", state.sequence_counter))]),
+        ];
+
+        let status_style = if state.is_halted {
+            Style::default().fg(Color::Red)
+        } else if state.is_running {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default().fg(Color::Yellow)
+        };
+
+        let status = if state.is_halted {
+            "HALTED"
+        } else if state.is_running {
+            "RUNNING"
+        } else {
+            "READY"
+        };
+
+        Table::new(rows)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Registers [{}]", status))
+                .title_style(status_style))
+            .widths(&[Constraint::Length(4), Constraint::Length(8)])
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(is_halted: bool) -> MachineState {
+        MachineState {
+            program_counter: 0x0A,
+            accumulator: 0x003C,
+            instruction_register: 0x7001,
+            address_register: 0,
+            data_register: 0,
+            extend_register: 0,
+            sequence_counter: 3,
+            is_halted,
+            is_running: false,
+            memory_snapshot: Vec::new(),
+        }
+    }
+
+    fn render(widget: RegistersWidget) -> Vec<String> {
+        let area = Rect::new(0, 0, 20, 9);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+
+        (0..area.height)
+            .map(|y| (1..area.width - 1).map(|x| buf.get(x, y).symbol.clone()).collect::<String>())
+            .map(|row| row.trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn title_shows_run_status() {
+        let state = state(true);
+        assert!(render(RegistersWidget::new(&state))[0].starts_with("Registers [HALTED]"));
+
+        let state = MachineState { is_halted: false, ..state };
+        assert!(render(RegistersWidget::new(&state))[0].starts_with("Registers [READY]"));
+    }
+
+    #[test]
+    fn registers_use_the_radix() {
+        let state = state(false);
+
+        let rows = render(RegistersWidget::new(&state));
+        assert_eq!(rows[1], "PC   0x000A");
+        assert_eq!(rows[7], "SC   3");
+
+        let rows = render(RegistersWidget::new(&state).radix(Radix::Oct));
+        assert_eq!(rows[1], "PC   0o000012");
+        assert_eq!(rows[2], "AC   0o000074");
+    }
+}
@@ -1,9 +1,12 @@
 use leptos::*;
 use wasm_bindgen::JsCast;
 
+use mano_common::radix::Radix;
+
 #[component]
 pub fn Assembly(
-    assembled_program: ReadSignal<Vec<String>>,
+    #[prop(into)] assembled_program: Signal<Vec<String>>,
+    #[prop(into, optional)] radix: MaybeSignal<Radix>,
 ) -> impl IntoView {
     let scroll_container = create_node_ref::<html::Div>();
 
@@ -22,6 +25,7 @@ pub fn Assembly(
             <div class="assembly-content scrollable" node_ref=scroll_container>
                 {move || {
                     let program = assembled_program.get();
+                    let radix = radix.get();
                     if program.is_empty() {
                         view! {
                             <div class="assembly-empty">"No assembled program"</div>
@@ -31,7 +35,7 @@ pub fn Assembly(
                             <div class="assembly-table">
                                 <div class="assembly-header">
                                     <span class="assembly-col-lin">"LIN"</span>
-                                    <span class="assembly-col-hex">"HEX"</span>
+                                    <span class="assembly-col-hex">{radix.name()}</span>
                                     <span class="assembly-col-dec">"DEC"</span>
                                 </div>
                                 {program.iter()
@@ -42,8 +46,8 @@ pub fn Assembly(
 
                                         view! {
                                             <div class="assembly-row">
-                                                <span class="assembly-col-lin">{format!("[{:04X}]", addr)}</span>
-                                                <span class="assembly-col-hex">{format!("{:04X}", hex_value)}</span>
+                                                <span class="assembly-col-lin">{format!("[{}]", radix.address(addr))}</span>
+                                                <span class="assembly-col-hex">{radix.word(hex_value)}</span>
                                                 <span class="assembly-col-dec">{format!("{}", dec_value)}</span>
                                             </div>
                                         }
@@ -2,13 +2,13 @@ use leptos::*;
 
 #[component]
 pub fn Editor(
-    source_code: ReadSignal<String>,
-    set_source_code: WriteSignal<String>,
+    #[prop(into)] source_code: Signal<String>,
+    #[prop(into)] on_change: Callback<String>,
 ) -> impl IntoView {
     let line_count = move || source_code.get().lines().count().max(1);
 
     let on_input = move |ev| {
-        set_source_code.set(event_target_value(&ev));
+        on_change.call(event_target_value(&ev));
     };
 
     // Syntax highlighting function
@@ -0,0 +1,18 @@
+//! Reusable Leptos components for Mano Machine simulator frontends.
+//!
+//! Props take `Signal`s and `Callback`s rather than a particular app's
+//! signal pairs, so panes can be composed freely. The class names match the
+//! stylesheets shipped with `mano-web`.
+
+pub mod assembly;
+pub mod editor;
+pub mod messages;
+pub mod registers;
+pub mod state;
+
+pub use assembly::Assembly;
+pub use editor::Editor;
+pub use messages::MessagesPane;
+pub use state::State;
+
+pub use mano_common::radix::Radix;
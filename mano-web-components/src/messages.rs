@@ -4,8 +4,8 @@ use wasm_bindgen::JsCast;
 
 #[component]
 pub fn MessagesPane(
-    messages: ReadSignal<Messages>,
-    debug_mode: ReadSignal<bool>,
+    #[prop(into)] messages: Signal<Messages>,
+    #[prop(into)] debug_mode: Signal<bool>,
 ) -> impl IntoView {
     let scroll_container = create_node_ref::<html::Div>();
 
@@ -0,0 +1,36 @@
+/// Reference information for a Mano Machine register or flip-flop.
+pub struct RegisterDoc {
+    pub name: &'static str,
+    pub title: &'static str,
+    pub bits: u8,
+    pub description: &'static str,
+}
+
+impl RegisterDoc {
+    /// One-line summary suitable for a tooltip.
+    pub fn tooltip(&self) -> String {
+        format!("{} ({} bit{}): {}", self.title, self.bits, if self.bits == 1 { "" } else { "s" }, self.description)
+    }
+}
+
+pub const REGISTERS: &[RegisterDoc] = &[
+    RegisterDoc { name: "PC", title: "Program Counter", bits: 12, description: "Address of the next instruction to fetch" },
+    RegisterDoc { name: "IR", title: "Instruction Register", bits: 16, description: "Instruction code fetched from memory" },
+    RegisterDoc { name: "SC", title: "Sequence Counter", bits: 4, description: "Generates the timing signals T0-T15 of the current instruction" },
+    RegisterDoc { name: "AC", title: "Accumulator", bits: 16, description: "Processor register used by arithmetic and logic instructions" },
+    RegisterDoc { name: "DR", title: "Data Register", bits: 16, description: "Holds the memory operand" },
+    RegisterDoc { name: "TR", title: "Temporary Register", bits: 16, description: "Holds temporary data, e.g. during the interrupt cycle" },
+    RegisterDoc { name: "E", title: "Extended Accumulator", bits: 1, description: "Receives the carry out of AC and takes part in circulates" },
+    RegisterDoc { name: "AR", title: "Address Register", bits: 12, description: "Address of the memory word being read or written" },
+    RegisterDoc { name: "INPR", title: "Input Register", bits: 8, description: "Character received from the input device" },
+    RegisterDoc { name: "OUTR", title: "Output Register", bits: 8, description: "Character sent to the output device" },
+    RegisterDoc { name: "FGI", title: "Input Flag", bits: 1, description: "Set when INPR holds a new character" },
+    RegisterDoc { name: "FGO", title: "Output Flag", bits: 1, description: "Set when the output device is ready for OUTR" },
+    RegisterDoc { name: "IEN", title: "Interrupt Enable", bits: 1, description: "Set by ION and cleared by IOF" },
+    RegisterDoc { name: "R", title: "Interrupt Flip-Flop", bits: 1, description: "Selects the interrupt cycle instead of a fetch" },
+    RegisterDoc { name: "S", title: "Start-Stop Flip-Flop", bits: 1, description: "Cleared by HLT to stop the computer" },
+];
+
+pub fn register_doc(name: &str) -> Option<&'static RegisterDoc> {
+    REGISTERS.iter().find(|doc| doc.name == name)
+}
@@ -0,0 +1,118 @@
+use leptos::*;
+use std::collections::HashSet;
+
+use mano_common::radix::Radix;
+use mano_lib::machine::MachineState;
+
+use crate::registers::register_doc;
+
+/// Register groups shown in the CPU section, in display order.
+const REGISTER_GROUPS: [(&str, &[&str]); 3] = [
+    ("Program Control", &["PC", "IR", "SC"]),
+    ("Data", &["AC", "DR", "E"]),
+    ("Memory", &["AR"]),
+];
+
+#[component]
+pub fn State(
+    #[prop(into)] machine_state: Signal<Option<MachineState>>,
+    #[prop(into)] debug_mode: Signal<bool>,
+    #[prop(into, optional)] radix: MaybeSignal<Radix>,
+) -> impl IntoView {
+    // Kept outside the state view so groups stay collapsed across updates
+    let collapsed = create_rw_signal(HashSet::<&'static str>::new());
+
+    view! {
+        <div class="state-pane">
+            <div class="pane-title-bar">
+                <h2 class="pane-title">"Machine State"</h2>
+                {move || if debug_mode.get() {
+                    view! {
+                        <div class="debug-indicator">
+                            <span class="debug-light"></span>
+                            <span class="debug-label">"Debug Mode"</span>
+                        </div>
+                    }.into_view()
+                } else {
+                    view! { <></> }.into_view()
+                }}
+            </div>
+            <div class="state-content scrollable">
+                {move || {
+                    let radix = radix.get();
+                    match machine_state.get() {
+                        None => view! {
+                            <div class="state-empty">"No state available"</div>
+                        }.into_view(),
+                        Some(state) => view! {
+                            <div class="state-display-horizontal">
+                                <div class="cpu-state">
+                                    <h3 class="state-section-title">"CPU"</h3>
+                                    {REGISTER_GROUPS.iter().map(|(group, names)| {
+                                        let group = *group;
+                                        view! {
+                                            <div class="register-group" class:collapsed=move || collapsed.with(|c| c.contains(group))>
+                                                <button
+                                                    class="register-group-title"
+                                                    on:click=move |_| collapsed.update(|c| if !c.remove(group) { c.insert(group); })
+                                                >
+                                                    {group}
+                                                </button>
+                                                <div class="registers-list">
+                                                    {names.iter().map(|name| view! {
+                                                        <div class="register-row" title=register_doc(name).map(|doc| doc.tooltip())>
+                                                            <span class="register-name">{*name}</span>
+                                                            <span class="register-value">{register_value(&state, name, radix)}</span>
+                                                        </div>
+                                                    }).collect_view()}
+                                                </div>
+                                            </div>
+                                        }
+                                    }).collect_view()}
+                                    <div class="registers-list">
+                                        <div class="register-row" title=register_doc("S").map(|doc| doc.tooltip())>
+                                            <span class="register-name">"Status"</span>
+                                            <span class="register-value register-status" class:halted=state.is_halted>
+                                                {if state.is_halted { "HALT" } else { "RUN" }}
+                                            </span>
+                                        </div>
+                                    </div>
+                                </div>
+
+                                <div class="memory-state">
+                                    <h3 class="state-section-title">"Memory"</h3>
+                                    <div class="memory-dump-lengthwise">
+                                        {
+                                            let memory = &state.memory_snapshot;
+                                            (0..memory.len()).map(|addr| {
+                                                view! {
+                                                    <div class="memory-line">
+                                                        <span class="mem-addr-inline">{radix.format(addr as u16, 2)}</span>
+                                                        <span class="mem-value-inline">{radix.word(memory[addr])}</span>
+                                                    </div>
+                                                }
+                                            }).collect_view()
+                                        }
+                                    </div>
+                                </div>
+                            </div>
+                        }.into_view()
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
+
+fn register_value(state: &MachineState, name: &str, radix: Radix) -> String {
+    match name {
+        "PC" => radix.word(state.program_counter),
+        "AC" => radix.word(state.accumulator),
+        "IR" => radix.word(state.instruction_register),
+        "AR" => radix.word(state.address_register),
+        "DR" => radix.word(state.data_register),
+        "E" => radix.word(state.extend_register),
+        "SC" => format!("{}", state.sequence_counter),
+        _ => String::new(),
+    }
+}
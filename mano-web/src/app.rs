@@ -1,11 +1,14 @@
 use leptos::*;
-use mano_lib::{machine::Machine, message::Messages};
+use mano_lib::{machine::Machine, message::{Level, Messages}};
+
+use mano_common::limits::DEFAULT_MAX_STEPS;
+use mano_web_components::{Editor, Radix};
 
 use crate::components::{
-    editor::Editor,
     toolbar::Toolbar,
     output::Output,
 };
+use crate::session::{self, Action, Recording};
 
 #[component]
 pub fn App() -> impl IntoView {
@@ -44,6 +47,17 @@ END                /End of symbolic program"
     };
     let (machine_state, set_machine_state) = create_signal(initial_state);
     let (is_running, set_is_running) = create_signal(false);
+    let (radix, set_radix) = create_signal(Radix::Hex);
+    let (max_steps, set_max_steps) = create_signal(DEFAULT_MAX_STEPS);
+    let (is_recording, set_is_recording) = create_signal(false);
+    let (recording, set_recording) = create_signal(None::<Recording>);
+    let has_recording = Signal::derive(move || recording.with(Option::is_some));
+
+    let record = move |action: Action| {
+        if is_recording.get_untracked() {
+            set_recording.update(|r| if let Some(r) = r { r.record(action) });
+        }
+    };
 
     // Machine instance (stored without cloning)
     let machine = store_value(Machine::new());
@@ -71,6 +85,11 @@ END                /End of symbolic program"
         let assembled = machine.with_value(|m| m.get_assembled_program().to_vec());
         let state = machine.with_value(|m| m.get_state());
 
+        record(Action::Assemble {
+            source: code,
+            errors: msgs.entries.iter().filter(|(level, _)| matches!(level, Level::Error)).count(),
+        });
+
         set_messages.set(msgs);
         set_assembled_program.set(assembled);
         set_machine_state.set(Some(state));
@@ -84,13 +103,13 @@ END                /End of symbolic program"
         set_is_running.set(true);
 
         // Run the machine and collect results
+        let max_steps = max_steps.get_untracked();
         let all_messages = {
             use std::cell::RefCell;
             let msgs_cell = RefCell::new(messages.get());
 
             machine.update_value(|m| {
                 let mut step_count = 0;
-                let max_steps = 10000;
 
                 while !m.is_halted() && step_count < max_steps {
                     let mut step_messages = Messages::new();
@@ -103,6 +122,14 @@ END                /End of symbolic program"
 
                     step_count += 1;
                 }
+
+                // Not an error, so Run can be pressed again to carry on
+                if step_count == max_steps && !m.is_halted() {
+                    msgs_cell.borrow_mut().entries.push((
+                        Level::Info,
+                        format!("Run stopped after {} steps without halting", max_steps),
+                    ));
+                }
             });
 
             msgs_cell.into_inner()
@@ -110,6 +137,11 @@ END                /End of symbolic program"
 
         let final_state = machine.with_value(|m| m.get_state());
 
+        record(Action::Run {
+            program_counter: final_state.program_counter,
+            halted: final_state.is_halted,
+        });
+
         set_messages.set(all_messages);
         set_machine_state.set(Some(final_state));
         set_is_running.set(false);
@@ -131,6 +163,11 @@ END                /End of symbolic program"
 
         let state = machine.with_value(|m| m.get_state());
 
+        record(Action::Step {
+            program_counter: state.program_counter,
+            sequence_counter: u16::from(state.sequence_counter),
+        });
+
         set_messages.set(step_messages);
         set_machine_state.set(Some(state));
     };
@@ -160,12 +197,46 @@ END                /End of symbolic program"
             }
         });
         set_machine_state.set(Some(zeroed_state));
+
+        record(Action::Reset);
     };
 
     let toggle_debug = move || {
         set_debug_mode.update(|d| *d = !*d);
     };
 
+    let toggle_radix = move || {
+        set_radix.update(|r| *r = r.toggle());
+    };
+
+    let edit_source = move |code: String| {
+        record(Action::Edit { length: code.len() });
+        set_source_code.set(code);
+    };
+
+    // Starting a new recording replaces the previous one; stopping keeps it for export
+    let toggle_recording = move || {
+        if !is_recording.get() {
+            set_recording.set(Some(Recording::start()));
+        }
+        set_is_recording.update(|r| *r = !*r);
+    };
+
+    let export_session = move || {
+        let json = recording.with(|r| r.as_ref().map(|r| {
+            r.export(
+                source_code.get(),
+                assembled_program.get(),
+                &messages.get(),
+                machine_state.get().as_ref(),
+            )
+        }));
+
+        if let Some(json) = json {
+            session::download("mano-session.json", &json);
+        }
+    };
+
     view! {
         <div class="app">
             <div class="title-bar">
@@ -178,7 +249,7 @@ END                /End of symbolic program"
                 <div class="input-group">
                     <Editor
                         source_code=source_code
-                        set_source_code=set_source_code
+                        on_change=edit_source
                     />
                 </div>
                 <div class="output-group">
@@ -187,6 +258,7 @@ END                /End of symbolic program"
                         messages=messages
                         assembled_program=assembled_program
                         machine_state=machine_state
+                        radix=radix
                     />
                 </div>
             </div>
@@ -199,6 +271,14 @@ END                /End of symbolic program"
                     debug_mode=debug_mode
                     on_toggle_debug=toggle_debug
                     is_running=is_running
+                    is_recording=is_recording
+                    has_recording=has_recording
+                    on_toggle_recording=toggle_recording
+                    on_export=export_session
+                    radix=radix
+                    on_toggle_radix=toggle_radix
+                    max_steps=max_steps
+                    on_max_steps=move |steps| set_max_steps.set(steps)
                 />
                 <div class="copyright">"COPYRIGHT HSM SYSTEMS 1978"</div>
             </div>
@@ -1,6 +1,2 @@
-pub mod editor;
 pub mod toolbar;
 pub mod output;
-pub mod messages;
-pub mod assembly;
-pub mod state;
@@ -1,7 +1,7 @@
 use leptos::*;
 use mano_lib::{machine::MachineState, message::Messages};
 
-use super::{assembly::Assembly, messages::MessagesPane, state::State};
+use mano_web_components::{Assembly, MessagesPane, Radix, State};
 
 #[component]
 pub fn Output(
@@ -9,6 +9,7 @@ pub fn Output(
     messages: ReadSignal<Messages>,
     assembled_program: ReadSignal<Vec<String>>,
     machine_state: ReadSignal<Option<MachineState>>,
+    radix: ReadSignal<Radix>,
 ) -> impl IntoView {
     view! {
         <div class="output-container">
@@ -18,12 +19,12 @@ pub fn Output(
                         <MessagesPane messages=messages debug_mode=debug_mode />
                     </div>
                     <div class="output-pane output-assembly">
-                        <Assembly assembled_program=assembled_program />
+                        <Assembly assembled_program=assembled_program radix=radix />
                     </div>
                 </div>
                 <div class="output-bottom">
                     <div class="output-pane output-state">
-                        <State machine_state=machine_state debug_mode=debug_mode />
+                        <State machine_state=machine_state debug_mode=debug_mode radix=radix />
                     </div>
                 </div>
             </div>
@@ -1,4 +1,5 @@
 use leptos::*;
+use mano_web_components::Radix;
 
 #[component]
 pub fn Toolbar(
@@ -9,7 +10,17 @@ pub fn Toolbar(
     debug_mode: ReadSignal<bool>,
     on_toggle_debug: impl Fn() + 'static + Clone,
     is_running: ReadSignal<bool>,
+    is_recording: ReadSignal<bool>,
+    has_recording: Signal<bool>,
+    on_toggle_recording: impl Fn() + 'static + Clone,
+    on_export: impl Fn() + 'static + Clone,
+    radix: ReadSignal<Radix>,
+    on_toggle_radix: impl Fn() + 'static + Clone,
+    max_steps: ReadSignal<usize>,
+    on_max_steps: impl Fn(usize) + 'static,
 ) -> impl IntoView {
+    let max_steps_input = create_node_ref::<html::Input>();
+
     view! {
         <div class="toolbar">
             <div class="toolbar-buttons">
@@ -55,6 +66,49 @@ pub fn Toolbar(
                 </button>
             </div>
             <div class="toolbar-controls">
+                <label class="toolbar-limit" title="Clock cycles a Run may take before it stops">
+                    "Max steps"
+                    <input
+                        class="toolbar-input"
+                        type="number"
+                        min="1"
+                        node_ref=max_steps_input
+                        prop:value=move || max_steps.get().to_string()
+                        on:change=move |ev| {
+                            match event_target_value(&ev).trim().parse::<usize>() {
+                                Ok(steps) if steps > 0 => on_max_steps(steps),
+                                // Show the limit still in effect rather than the rejected text
+                                _ => if let Some(input) = max_steps_input.get() {
+                                    input.set_value(&max_steps.get_untracked().to_string());
+                                },
+                            }
+                        }
+                    />
+                </label>
+                <button
+                    class="debug-toggle-button"
+                    class:pressed=move || radix.get() == Radix::Oct
+                    on:click=move |_| on_toggle_radix()
+                    title="Show registers, memory and the assembled program in octal"
+                >
+                    "Octal"
+                </button>
+                <button
+                    class="toolbar-button"
+                    on:click=move |_| on_export()
+                    disabled=move || !has_recording.get()
+                    title="Download the recorded session as JSON"
+                >
+                    "Export"
+                </button>
+                <button
+                    class="debug-toggle-button"
+                    class:pressed=move || is_recording.get()
+                    on:click=move |_| on_toggle_recording()
+                    title="Record assembles, steps and edits with timestamps"
+                >
+                    "Record"
+                </button>
                 <button
                     class="debug-toggle-button"
                     class:pressed=move || debug_mode.get()
@@ -1,5 +1,6 @@
 mod app;
 mod components;
+mod session;
 
 use leptos::*;
 use wasm_bindgen::prelude::wasm_bindgen;
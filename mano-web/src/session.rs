@@ -0,0 +1,144 @@
+use leptos::*;
+use mano_lib::machine::MachineState;
+use mano_lib::message::{Level, Messages};
+use serde::Serialize;
+
+/// A user interaction captured while recording.
+#[derive(Clone, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum Action {
+    Edit { length: usize },
+    Assemble { source: String, errors: usize },
+    Run { program_counter: u16, halted: bool },
+    Step { program_counter: u16, sequence_counter: u16 },
+    Reset,
+}
+
+#[derive(Clone, Serialize)]
+pub struct Event {
+    /// Milliseconds since recording started
+    pub time_ms: f64,
+    #[serde(flatten)]
+    pub action: Action,
+}
+
+/// Timestamped log of interactions, exported with the final artifacts so
+/// students can submit evidence of their debugging process.
+#[derive(Clone)]
+pub struct Recording {
+    started_at: String,
+    started_ms: f64,
+    events: Vec<Event>,
+}
+
+impl Recording {
+    pub fn start() -> Self {
+        Self {
+            started_at: js_sys::Date::new_0().to_iso_string().into(),
+            started_ms: js_sys::Date::now(),
+            events: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, action: Action) {
+        let event = Event {
+            time_ms: js_sys::Date::now() - self.started_ms,
+            action,
+        };
+
+        // Collapse a run of keystrokes into a single edit
+        if let (Some(Event { action: Action::Edit { .. }, .. }), Action::Edit { .. }) = (self.events.last(), &event.action) {
+            self.events.pop();
+        }
+
+        self.events.push(event);
+    }
+
+    /// Serialise the recording and the current artifacts as pretty JSON.
+    pub fn export(
+        &self,
+        source: String,
+        assembled_program: Vec<String>,
+        messages: &Messages,
+        final_state: Option<&MachineState>,
+    ) -> String {
+        let session = Session {
+            started_at: &self.started_at,
+            events: &self.events,
+            source,
+            assembled_program,
+            messages: messages.entries
+                .iter()
+                .map(|(level, msg)| MessageRecord {
+                    level: match level {
+                        Level::Info => "info",
+                        Level::Error => "error",
+                        Level::Debug => "debug",
+                    },
+                    message: msg.clone(),
+                })
+                .collect(),
+            final_state: final_state.map(StateRecord::from),
+        };
+
+        serde_json::to_string_pretty(&session).unwrap_or_default()
+    }
+}
+
+#[derive(Serialize)]
+struct Session<'a> {
+    started_at: &'a str,
+    events: &'a [Event],
+    source: String,
+    assembled_program: Vec<String>,
+    messages: Vec<MessageRecord>,
+    final_state: Option<StateRecord>,
+}
+
+#[derive(Serialize)]
+struct MessageRecord {
+    level: &'static str,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct StateRecord {
+    program_counter: u16,
+    accumulator: u16,
+    instruction_register: u16,
+    address_register: u16,
+    data_register: u16,
+    extend_register: u16,
+    sequence_counter: u16,
+    is_halted: bool,
+    memory_snapshot: Vec<u16>,
+}
+
+impl From<&MachineState> for StateRecord {
+    fn from(state: &MachineState) -> Self {
+        Self {
+            program_counter: state.program_counter,
+            accumulator: state.accumulator,
+            instruction_register: state.instruction_register,
+            address_register: state.address_register,
+            data_register: state.data_register,
+            extend_register: state.extend_register,
+            sequence_counter: u16::from(state.sequence_counter),
+            is_halted: state.is_halted,
+            memory_snapshot: state.memory_snapshot.clone(),
+        }
+    }
+}
+
+/// Offer `contents` to the browser as a file download.
+pub fn download(filename: &str, contents: &str) {
+    let href = format!(
+        "data:application/json;charset=utf-8,{}",
+        String::from(js_sys::encode_uri_component(contents))
+    );
+
+    let link = html::a();
+    link.set_href(&href);
+    link.set_download(filename);
+    link.click();
+}